@@ -15,13 +15,27 @@
 //! [here]: https://discord.com/developers/docs/resources/channel#embed-object
 
 use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
 
-use crate::json::{self, from_number, json, Value};
-use crate::model::channel::Embed;
-use crate::model::Timestamp;
+use crate::constants;
+use crate::internal::prelude::*;
+use crate::json::{self, from_number, json, JsonMap, Value};
+use crate::model::channel::{
+    images_without_url,
+    text_part_violations,
+    Embed,
+    EmbedField,
+    EmbedFieldKind,
+};
+use crate::model::{ModelError, Timestamp};
 #[cfg(feature = "utils")]
 use crate::utils::Colour;
 
+/// The key under which [`CreateEmbed::auto_truncate`] is stored in the
+/// builder's map. It is not part of the embed and left out when sending it.
+const AUTO_TRUNCATE: &str = "__serenity_auto_truncate";
+
 /// A builder to create a fake [`Embed`] object, for use with the
 /// [`ChannelId::send_message`] and [`ExecuteWebhook::embeds`] methods.
 ///
@@ -71,6 +85,57 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the colour of the left-hand side of the embed by name, such as
+    /// `"gold"` or `"dark green"`.
+    ///
+    /// See [`Colour::from_name`] for the known names. Unknown names fall back
+    /// to the default colour, [`Colour::default`].
+    #[cfg(feature = "utils")]
+    pub fn colour_named(&mut self, name: &str) -> &mut Self {
+        self.colour(Colour::from_name(name).unwrap_or_default())
+    }
+
+    /// Set the colour of the left-hand side of the embed by name.
+    ///
+    /// This is an alias of [`Self::colour_named`].
+    #[cfg(feature = "utils")]
+    #[inline]
+    pub fn color_named(&mut self, name: &str) -> &mut Self {
+        self.colour_named(name)
+    }
+
+    /// Set the colour of the left-hand side of the embed to the average
+    /// colour of an image, e.g. the one shown in the embed.
+    ///
+    /// Fully transparent pixels are ignored. PNG and JPEG images are
+    /// supported.
+    ///
+    /// Requires the `image_colour` feature be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Image`] if the image could not be decoded.
+    ///
+    /// [`Error::Image`]: crate::Error::Image
+    #[cfg(all(feature = "image_colour", feature = "utils"))]
+    pub fn colour_from_image_bytes(&mut self, bytes: &[u8]) -> Result<&mut Self> {
+        let image = image::load_from_memory(bytes)?.to_rgba8();
+
+        let mut sums = [0_u64; 3];
+        let mut count = 0_u64;
+        for pixel in image.pixels().filter(|pixel| pixel[3] != 0) {
+            for (sum, &channel) in sums.iter_mut().zip(&pixel.0[..3]) {
+                *sum += u64::from(channel);
+            }
+            count += 1;
+        }
+
+        // The average of `u8` values always fits into a `u8`.
+        let average = |sum: u64| sum.checked_div(count).unwrap_or(0) as u8;
+
+        Ok(self.colour(Colour::from_rgb(average(sums[0]), average(sums[1]), average(sums[2]))))
+    }
+
     #[cfg(feature = "utils")]
     fn _colour(&mut self, colour: Colour) {
         self.0.insert("color", from_number(u64::from(colour.0)));
@@ -106,7 +171,10 @@ impl CreateEmbed {
     /// add to them.
     ///
     /// **Note**: Maximum amount of characters you can put is 256 in a field
-    /// name and 1024 in a field value.
+    /// name and 1024 in a field value, and an embed can have at most 25
+    /// fields. These limits are not checked here, use [`Self::try_field`] to
+    /// catch them before sending the embed, or [`Self::auto_truncate`] to cut
+    /// names and values down to size.
     #[inline]
     pub fn field<T, U>(&mut self, name: T, value: U, inline: bool) -> &mut Self
     where
@@ -117,7 +185,63 @@ impl CreateEmbed {
         self
     }
 
+    /// Set a field like [`Self::field`], checking the field against Discord's
+    /// limits first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedFieldAmount`] if the embed already has 25
+    /// fields.
+    ///
+    /// Returns a [`ModelError::EmbedFieldTooLong`] if the name is over 256 or
+    /// the value is over 1024 unicode code points, unless
+    /// [`Self::auto_truncate`] is enabled. The field is not added in either
+    /// case.
+    pub fn try_field<T, U>(
+        &mut self,
+        name: T,
+        value: U,
+        inline: bool,
+    ) -> StdResult<&mut Self, ModelError>
+    where
+        T: ToString,
+        U: ToString,
+    {
+        let index = self.field_count();
+        if index >= constants::EMBED_MAX_FIELD_COUNT {
+            return Err(ModelError::EmbedFieldAmount);
+        }
+
+        let name = name.to_string();
+        let value = value.to_string();
+
+        if self.0.contains_key(AUTO_TRUNCATE) {
+            return Ok(self.field(name, value, inline));
+        }
+
+        let parts = [
+            (EmbedFieldKind::FieldName(index), name.as_str()),
+            (EmbedFieldKind::FieldValue(index), value.as_str()),
+        ];
+        if let Some(&(field, len, limit)) = text_part_violations(&parts).first() {
+            return Err(ModelError::EmbedFieldTooLong {
+                field,
+                len,
+                limit,
+            });
+        }
+
+        Ok(self.field(name, value, inline))
+    }
+
     fn _field(&mut self, name: String, value: String, inline: bool) {
+        let (name, value) = if self.0.contains_key(AUTO_TRUNCATE) {
+            let field = EmbedField::new_truncated(name, value, inline);
+            (field.name, field.value)
+        } else {
+            (name, value)
+        };
+
         {
             let entry = self.0.entry("fields").or_insert_with(|| Value::from(Vec::<Value>::new()));
 
@@ -131,22 +255,142 @@ impl CreateEmbed {
         }
     }
 
+    /// Sets whether field names and values added from now on are cut down to
+    /// their maximum lengths of 256 and 1024 unicode code points, as done by
+    /// [`EmbedField::new_truncated`]. This applies to [`Self::field`],
+    /// [`Self::try_field`] and [`Self::fields`].
+    ///
+    /// This is useful for displaying untrusted or variable-length data.
+    ///
+    /// Defaults to `false`, in which case fields are added as given.
+    ///
+    /// [`EmbedField::new_truncated`]: crate::model::channel::EmbedField::new_truncated
+    pub fn auto_truncate(&mut self, truncate: bool) -> &mut Self {
+        if truncate {
+            self.0.insert(AUTO_TRUNCATE, Value::from(true));
+        } else {
+            self.0.remove(AUTO_TRUNCATE);
+        }
+
+        self
+    }
+
+    /// Converts the builder into the JSON object sent to Discord, leaving out
+    /// the [`Self::auto_truncate`] setting.
+    pub(crate) fn into_json_map(mut self) -> JsonMap {
+        self.0.remove(AUTO_TRUNCATE);
+        json::hashmap_to_json_map(self.0)
+    }
+
     /// Adds multiple fields at once.
     ///
     /// This is sugar to reduce the need of calling [`Self::field`] manually multiple times.
+    ///
+    /// **Note**: An embed can have at most 25 fields. Once the embed holds that many fields,
+    /// the remaining items of `fields` are ignored.
     pub fn fields<T, U, It>(&mut self, fields: It) -> &mut Self
     where
         It: IntoIterator<Item = (T, U, bool)>,
         T: ToString,
         U: ToString,
     {
-        for (name, value, inline) in fields {
+        let remaining = constants::EMBED_MAX_FIELD_COUNT.saturating_sub(self.field_count());
+
+        for (name, value, inline) in fields.into_iter().take(remaining) {
             self.field(name, value, inline);
         }
 
         self
     }
 
+    /// Returns the number of unicode code points Discord counts towards the
+    /// maximum textual length of an embed, which is 6000.
+    ///
+    /// See [`Embed::len`] for what is counted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        embed_length(|key| self.0.get(key))
+    }
+
+    /// Returns whether the embed has no text that counts towards its maximum
+    /// length, see [`Self::len`].
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks the embed against all of Discord's limits at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns every limit the embed exceeds: each part over its own maximum
+    /// length (see [`EmbedFieldKind::limit`]), more than 25 fields, and a total
+    /// textual length over 6000 unicode code points.
+    pub fn validate(&self) -> StdResult<(), Vec<EmbedValidationError>> {
+        let parts = embed_text_parts(|key| self.0.get(key));
+        let mut errors: Vec<_> = text_part_violations(&parts)
+            .into_iter()
+            .map(|(field, len, limit)| EmbedValidationError::TooLong {
+                field,
+                len,
+                limit,
+            })
+            .collect();
+
+        let field_count = self.field_count();
+        if field_count > constants::EMBED_MAX_FIELD_COUNT {
+            errors.push(EmbedValidationError::TooManyFields(field_count));
+        }
+
+        let total = self.len();
+        if total > constants::EMBED_MAX_LENGTH {
+            errors.push(EmbedValidationError::TooLarge(total - constants::EMBED_MAX_LENGTH));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the indices of the embeds that have an image but no URL, if
+    /// there is more than one embed.
+    ///
+    /// When a message has multiple embeds, Discord only groups their images
+    /// into a gallery if the embeds share the same [`Self::url`]. The images of
+    /// the returned embeds would silently be dropped from the gallery.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::CreateEmbed;
+    ///
+    /// let mut first = CreateEmbed::default();
+    /// first.url("https://example.com").image("https://example.com/a.png");
+    /// let mut second = CreateEmbed::default();
+    /// second.image("https://example.com/b.png");
+    ///
+    /// assert_eq!(CreateEmbed::images_without_url(&[first, second]), [1]);
+    /// ```
+    #[must_use]
+    pub fn images_without_url(embeds: &[CreateEmbed]) -> Vec<usize> {
+        let embeds: Vec<_> = embeds
+            .iter()
+            .map(|embed| (embed.0.contains_key("image"), embed.0.contains_key("url")))
+            .collect();
+
+        images_without_url(&embeds)
+    }
+
+    fn field_count(&self) -> usize {
+        match self.0.get("fields") {
+            Some(Value::Array(fields)) => fields.len(),
+            _ => 0,
+        }
+    }
+
     /// Build the footer of the embed.
     ///
     /// Refer to the documentation for [`CreateEmbedFooter`] for more
@@ -202,6 +446,9 @@ impl CreateEmbed {
     /// - `2004-06-08T16:04:23Z`
     /// - `2004-06-08T16:04:23Z`
     ///
+    /// The timestamp is sent to Discord in RFC 3339 form, and can be read back
+    /// from a received embed with [`Embed::timestamp()`].
+    ///
     /// # Examples
     ///
     /// Passing a string timestamp:
@@ -270,6 +517,21 @@ impl CreateEmbed {
         self.0.insert("timestamp", Value::from(timestamp.to_string()));
     }
 
+    /// Formats the timestamp set with [`Self::timestamp`] as Discord's
+    /// relative time markdown, e.g. `<t:1462015105:R>`.
+    ///
+    /// This can be used to also show the timestamp in the description or a
+    /// field, where clients render it as "5 minutes ago" and keep it up to
+    /// date.
+    ///
+    /// Returns [`None`] if no timestamp has been set.
+    #[must_use]
+    pub fn timestamp_markdown(&self) -> Option<String> {
+        let timestamp = Timestamp::parse(self.0.get("timestamp")?.as_str()?).ok()?;
+
+        Some(format!("<t:{}:R>", timestamp.unix_timestamp()))
+    }
+
     /// Set the title of the embed.
     #[inline]
     pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
@@ -298,6 +560,211 @@ impl CreateEmbed {
 
         self
     }
+
+    /// Creates an embed builder from a JSON object in the shape of an
+    /// [`Embed`], such as an embed template loaded from a file.
+    ///
+    /// This is the reverse of [`Embed::fake`]. Values that are not preserved
+    /// by the conversion from an [`Embed`] are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::builder::CreateEmbed;
+    /// use serenity::json::json;
+    ///
+    /// let template = json!({
+    ///     "title": "Welcome!",
+    ///     "fields": [{"name": "Rules", "value": "Be nice."}],
+    /// });
+    ///
+    /// let embed = CreateEmbed::from_value(&template)?;
+    /// # Ok::<(), serenity::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if the value is not an object or any of its
+    /// keys has a value of an unexpected type.
+    ///
+    /// [`Embed::fake`]: crate::model::channel::Embed::fake
+    pub fn from_value(value: &Value) -> Result<Self> {
+        let embed: Embed = json::from_value(value.clone())?;
+
+        Ok(Self::from(embed))
+    }
+
+    /// Creates an embed builder for a rich link card from scraped link
+    /// metadata.
+    ///
+    /// The title, description, image and URL are set from the preview if
+    /// present, and the site name is shown in the footer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::builder::{CreateEmbed, LinkPreview};
+    ///
+    /// let preview = LinkPreview {
+    ///     title: Some("Serenity".to_string()),
+    ///     site_name: Some("GitHub".to_string()),
+    ///     url: Some("https://github.com/serenity-rs/serenity".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let embed = CreateEmbed::from_link_preview(preview);
+    /// ```
+    #[must_use]
+    pub fn from_link_preview(preview: LinkPreview) -> Self {
+        let mut b = CreateEmbed::default();
+
+        if let Some(title) = preview.title {
+            b.title(title);
+        }
+
+        if let Some(description) = preview.description {
+            b.description(description);
+        }
+
+        if let Some(image_url) = preview.image_url {
+            b.image(image_url);
+        }
+
+        if let Some(url) = preview.url {
+            b.url(url);
+        }
+
+        if let Some(site_name) = preview.site_name {
+            b.footer(|f| f.text(site_name));
+        }
+
+        b
+    }
+}
+
+/// Collects the parts of a serialized embed that Discord counts towards its
+/// maximum textual length.
+///
+/// `get` looks up the top-level keys of the embed.
+pub(crate) fn embed_text_parts<'a>(
+    get: impl Fn(&str) -> Option<&'a Value>,
+) -> Vec<(EmbedFieldKind, &'a str)> {
+    let text = |value: Option<&'a Value>| match value {
+        Some(Value::String(text)) => Some(text.as_str()),
+        _ => None,
+    };
+    let nested = |key: &str, inner: &str| match get(key) {
+        Some(Value::Object(object)) => text(object.get(inner)),
+        _ => None,
+    };
+
+    let mut parts = Vec::new();
+    let mut push = |kind, value: Option<&'a str>| {
+        if let Some(value) = value {
+            parts.push((kind, value));
+        }
+    };
+
+    push(EmbedFieldKind::Title, text(get("title")));
+    push(EmbedFieldKind::Description, text(get("description")));
+    push(EmbedFieldKind::AuthorName, nested("author", "name"));
+    push(EmbedFieldKind::FooterText, nested("footer", "text"));
+
+    if let Some(Value::Array(fields)) = get("fields") {
+        for (index, field) in fields.iter().enumerate() {
+            if let Value::Object(field) = field {
+                push(EmbedFieldKind::FieldName(index), text(field.get("name")));
+                push(EmbedFieldKind::FieldValue(index), text(field.get("value")));
+            }
+        }
+    }
+
+    parts
+}
+
+/// Counts the unicode code points of the parts of a serialized embed that
+/// Discord counts towards its maximum textual length.
+pub(crate) fn embed_length<'a>(get: impl Fn(&str) -> Option<&'a Value>) -> usize {
+    embed_text_parts(get).iter().map(|(_, text)| text.chars().count()).sum()
+}
+
+/// A limit of Discord's that an embed exceeds, as returned by
+/// [`CreateEmbed::validate`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedValidationError {
+    /// A part of the embed is over its own maximum length.
+    TooLong {
+        /// The part of the embed that is too long.
+        field: EmbedFieldKind,
+        /// The length of the part, in unicode code points.
+        len: usize,
+        /// The maximum length of the part, in unicode code points.
+        limit: usize,
+    },
+    /// The embed has more than 25 fields, containing the number of fields.
+    TooManyFields(usize),
+    /// The textual content of the embed is over 6000 unicode code points,
+    /// containing the number of code points over the limit.
+    TooLarge(usize),
+}
+
+impl fmt::Display for EmbedValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong {
+                field,
+                len,
+                limit,
+            } => write!(
+                f,
+                "Embed field {:?} is {} code points long, over its limit of {}.",
+                field, len, limit
+            ),
+            Self::TooManyFields(count) => {
+                write!(f, "Too many fields in an embed: {} of at most 25.", count)
+            },
+            Self::TooLarge(overflow) => {
+                write!(f, "Embed too large: {} code points over the limit of 6000.", overflow)
+            },
+        }
+    }
+}
+
+impl StdError for EmbedValidationError {}
+
+impl From<EmbedValidationError> for ModelError {
+    fn from(error: EmbedValidationError) -> ModelError {
+        match error {
+            EmbedValidationError::TooLong {
+                field,
+                len,
+                limit,
+            } => ModelError::EmbedFieldTooLong {
+                field,
+                len,
+                limit,
+            },
+            EmbedValidationError::TooManyFields(_) => ModelError::EmbedFieldAmount,
+            EmbedValidationError::TooLarge(overflow) => ModelError::EmbedTooLarge(overflow),
+        }
+    }
+}
+
+/// Metadata of a link, as scraped from its page, for building a rich link card
+/// with [`CreateEmbed::from_link_preview`].
+#[derive(Clone, Debug, Default)]
+pub struct LinkPreview {
+    /// The title of the page.
+    pub title: Option<String>,
+    /// A short description of the page.
+    pub description: Option<String>,
+    /// The URL of the preview image.
+    pub image_url: Option<String>,
+    /// The name of the site the page belongs to.
+    pub site_name: Option<String>,
+    /// The URL of the page.
+    pub url: Option<String>,
 }
 
 impl Default for CreateEmbed {
@@ -313,7 +780,10 @@ impl Default for CreateEmbed {
 impl From<Embed> for CreateEmbed {
     /// Converts the fields of an embed into the values for a new embed builder.
     ///
-    /// Some values - such as Proxy URLs - are not preserved.
+    /// Every value that can be sent to Discord is preserved, so re-sending the
+    /// builder reproduces the original embed. Values that Discord sets on its
+    /// own - such as proxy URLs, image sizes, the provider and the video - as
+    /// well as a timestamp that is not valid RFC 3339 are not preserved.
     fn from(embed: Embed) -> Self {
         let mut b = CreateEmbed::default();
 
@@ -349,7 +819,7 @@ impl From<Embed> for CreateEmbed {
             b.image(&image.url);
         }
 
-        if let Some(timestamp) = embed.timestamp {
+        if let Some(timestamp) = embed.timestamp.as_deref().and_then(|t| Timestamp::parse(t).ok()) {
             b.timestamp(timestamp);
         }
 
@@ -432,9 +902,17 @@ impl CreateEmbedFooter {
 
 #[cfg(test)]
 mod test {
-    use super::CreateEmbed;
+    use super::{CreateEmbed, AUTO_TRUNCATE};
     use crate::json::{self, json, Value};
-    use crate::model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo};
+    use crate::model::channel::{
+        Embed,
+        EmbedField,
+        EmbedFieldKind,
+        EmbedFooter,
+        EmbedImage,
+        EmbedVideo,
+    };
+    use crate::model::{ModelError, Timestamp};
     use crate::utils::Colour;
 
     #[test]
@@ -518,4 +996,246 @@ mod test {
 
         assert_eq!(built, obj);
     }
+
+    #[test]
+    fn test_from_value() {
+        let template = json!({
+            "title": "hakase",
+            "color": 0xFF0011,
+            "fields": [
+                {
+                    "name": "a",
+                    "value": "b",
+                },
+            ],
+            "footer": {
+                "text": "This is a hakase footer",
+            },
+        });
+
+        let builder = CreateEmbed::from_value(&template).unwrap();
+        let built = Value::from(json::hashmap_to_json_map(builder.0));
+
+        let obj = json!({
+            "color": 0xFF0011,
+            "title": "hakase",
+            "type": "rich",
+            "fields": [
+                {
+                    "inline": false,
+                    "name": "a",
+                    "value": "b",
+                },
+            ],
+            "footer": {
+                "text": "This is a hakase footer",
+            }
+        });
+
+        assert_eq!(built, obj);
+
+        assert!(CreateEmbed::from_value(&json!({"title": 5})).is_err());
+        assert!(CreateEmbed::from_value(&json!("title")).is_err());
+    }
+
+    #[test]
+    fn test_fields_limit() {
+        let mut builder = CreateEmbed::default();
+        builder.field("first", "value", false);
+        builder.fields((0..30).map(|i| (i, i, true)));
+
+        let fields = builder.0.get("fields").and_then(Value::as_array).unwrap();
+        assert_eq!(fields.len(), 25);
+        assert_eq!(fields[24]["name"], "23");
+    }
+
+    #[test]
+    fn test_len() {
+        let mut builder = CreateEmbed::default();
+        assert!(builder.is_empty());
+
+        builder
+            .title("caf\u{e9}")
+            .description("12345")
+            .author(|a| a.name("ab").url("https://example.com"))
+            .footer(|f| f.text("xyz"))
+            .field("n", "value", false);
+        assert_eq!(builder.len(), 20);
+
+        let embed: Embed =
+            json::from_value(Value::from(json::hashmap_to_json_map(builder.0))).unwrap();
+        assert_eq!(embed.len(), 20);
+    }
+
+    #[test]
+    fn test_timestamp_markdown() {
+        let mut builder = CreateEmbed::default();
+        assert_eq!(builder.timestamp_markdown(), None);
+
+        builder.timestamp(Timestamp::parse("2016-04-30T11:18:25Z").unwrap());
+        assert_eq!(builder.timestamp_markdown().as_deref(), Some("<t:1462015105:R>"));
+
+        let embed: Embed =
+            json::from_value(Value::from(json::hashmap_to_json_map(builder.0))).unwrap();
+        assert_eq!(embed.timestamp_relative_markdown().as_deref(), Some("<t:1462015105:R>"));
+    }
+
+    #[test]
+    fn test_from_embed_round_trip() {
+        let value = json!({
+            "author": {
+                "name": "hakase",
+                "url": "https://example.com/author",
+                "icon_url": "https://example.com/author.png",
+            },
+            "color": 0xFF0011,
+            "description": "description",
+            "fields": [{"name": "a", "value": "b", "inline": true}],
+            "footer": {"text": "footer", "icon_url": "https://example.com/footer.png"},
+            "image": {"url": "https://example.com/image.png"},
+            "thumbnail": {"url": "https://example.com/thumbnail.png"},
+            "timestamp": "2016-04-30T11:18:25.796Z",
+            "title": "title",
+            "type": "rich",
+            "url": "https://example.com",
+        });
+        let embed: Embed = json::from_value(value).unwrap();
+
+        let builder = CreateEmbed::from(embed.clone());
+        let round_tripped: Embed =
+            json::from_value(Value::from(json::hashmap_to_json_map(builder.0))).unwrap();
+
+        assert_eq!(json::to_value(round_tripped).unwrap(), json::to_value(embed).unwrap());
+    }
+
+    #[test]
+    fn test_from_embed_invalid_timestamp() {
+        let embed: Embed = json::from_value(json!({"timestamp": "yesterday"})).unwrap();
+
+        assert!(!CreateEmbed::from(embed).0.contains_key("timestamp"));
+    }
+
+    #[test]
+    fn test_try_field() {
+        let mut builder = CreateEmbed::default();
+
+        let err = builder.try_field("a".repeat(257), "b", false).unwrap_err();
+        assert!(matches!(err, ModelError::EmbedFieldTooLong {
+            field: EmbedFieldKind::FieldName(0),
+            len: 257,
+            limit: 256,
+        }));
+
+        for i in 0..25 {
+            builder.try_field(i, "c".repeat(1024), true).unwrap();
+        }
+
+        let err = builder.try_field("d", "e", false).unwrap_err();
+        assert!(matches!(err, ModelError::EmbedFieldAmount));
+        assert_eq!(builder.field_count(), 25);
+    }
+
+    #[test]
+    fn test_validate() {
+        use super::EmbedValidationError;
+
+        let mut builder = CreateEmbed::default();
+        builder.title("title").field("name", "value", false);
+        assert_eq!(builder.validate(), Ok(()));
+
+        builder.title("t".repeat(300)).description("d".repeat(4050));
+        for i in 0..26 {
+            builder.field(i, "v".repeat(if i == 3 { 1100 } else { 20 }), false);
+        }
+
+        assert_eq!(
+            builder.validate(),
+            Err(vec![
+                EmbedValidationError::TooLong {
+                    field: EmbedFieldKind::Title,
+                    len: 300,
+                    limit: 256,
+                },
+                EmbedValidationError::TooLong {
+                    field: EmbedFieldKind::FieldValue(4),
+                    len: 1100,
+                    limit: 1024,
+                },
+                EmbedValidationError::TooManyFields(27),
+                EmbedValidationError::TooLarge(1),
+            ])
+        );
+
+        let error = EmbedValidationError::TooLong {
+            field: EmbedFieldKind::FieldValue(4),
+            len: 1100,
+            limit: 1024,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Embed field FieldValue(4) is 1100 code points long, over its limit of 1024."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "image_colour")]
+    fn test_colour_from_image_bytes() {
+        use std::io::Cursor;
+
+        use image::{ImageOutputFormat, Rgba, RgbaImage};
+
+        let mut image = RgbaImage::from_pixel(2, 2, Rgba([200, 100, 0, 255]));
+        image.put_pixel(0, 0, Rgba([0, 100, 200, 255]));
+        image.put_pixel(1, 1, Rgba([255, 255, 255, 0]));
+
+        let mut png = Cursor::new(Vec::new());
+        image.write_to(&mut png, ImageOutputFormat::Png).unwrap();
+
+        let mut builder = CreateEmbed::default();
+        builder.colour_from_image_bytes(png.get_ref()).unwrap();
+        assert_eq!(builder.0.get("color"), Some(&json!(Colour::from_rgb(133, 100, 66).0)));
+
+        assert!(builder.colour_from_image_bytes(b"not an image").is_err());
+    }
+
+    #[test]
+    fn test_auto_truncate() {
+        let mut builder = CreateEmbed::default();
+        builder.field("a".repeat(300), "b", false);
+        builder.auto_truncate(true).field("c".repeat(300), "d".repeat(2000), true);
+        builder.try_field("e", "f".repeat(1025), false).unwrap();
+        builder.fields(vec![("g".repeat(257), "h", false)]);
+        builder.auto_truncate(false).field("i".repeat(300), "j", false);
+
+        builder.auto_truncate(true);
+        let map = builder.into_json_map();
+        assert!(!map.contains_key(AUTO_TRUNCATE));
+
+        let embed: Embed = json::from_value(Value::from(map)).unwrap();
+        assert_eq!(embed.fields[0].name.len(), 300);
+        assert_eq!(embed.fields[1].name.len(), 256);
+        assert_eq!(embed.fields[1].value.len(), 1024);
+        assert!(embed.fields[1].inline);
+        assert_eq!(embed.fields[2].value.len(), 1024);
+        assert_eq!(embed.fields[3].name.len(), 256);
+        assert_eq!(embed.fields[4].name.len(), 300);
+    }
+
+    #[test]
+    fn test_timestamp_round_trip() {
+        let timestamp = Timestamp::parse("2016-04-30T11:18:25.796Z").unwrap();
+
+        let mut builder = CreateEmbed::default();
+        builder.timestamp(timestamp);
+
+        let embed: Embed =
+            json::from_value(Value::from(json::hashmap_to_json_map(builder.0))).unwrap();
+        assert_eq!(embed.timestamp(), Some(timestamp));
+
+        let embed = Embed {
+            timestamp: Some("yesterday".to_string()),
+            ..embed
+        };
+        assert_eq!(embed.timestamp(), None);
+    }
 }
@@ -117,7 +117,7 @@ impl<'a> CreateInteractionResponseData<'a> {
 
     /// Adds an embed to the message.
     pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
-        let map = json::hashmap_to_json_map(embed.0);
+        let map = embed.into_json_map();
         let embed = Value::from(map);
 
         let embeds = self.0.entry("embeds").or_insert_with(|| Value::from(Vec::<Value>::new()));
@@ -143,7 +143,7 @@ impl<'a> CreateInteractionResponseData<'a> {
     /// Calling this will overwrite the embed list.
     /// To append embeds, call [`Self::add_embed`] instead.
     pub fn set_embed(&mut self, embed: CreateEmbed) -> &mut Self {
-        let map = json::hashmap_to_json_map(embed.0);
+        let map = embed.into_json_map();
         let embed = Value::from(map);
         self.0.insert("embeds", Value::from(vec![embed]));
 
@@ -157,7 +157,7 @@ impl<'a> CreateInteractionResponseData<'a> {
     pub fn set_embeds(&mut self, embeds: impl IntoIterator<Item = CreateEmbed>) -> &mut Self {
         let embeds = embeds
             .into_iter()
-            .map(|embed| json::hashmap_to_json_map(embed.0).into())
+            .map(|embed| embed.into_json_map().into())
             .collect::<Vec<Value>>();
 
         self.0.insert("embeds", Value::from(embeds));
@@ -72,7 +72,7 @@ impl<'a> CreateMessage<'a> {
     }
 
     fn _add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
-        let map = json::hashmap_to_json_map(embed.0);
+        let map = embed.into_json_map();
         let embed = Value::from(map);
 
         let embeds = self.0.entry("embeds").or_insert_with(|| Value::from(Vec::<Value>::new()));
@@ -247,6 +247,16 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Adds or removes the [`MessageFlags::SUPPRESS_NOTIFICATIONS`] flag,
+    /// keeping any other flags that have been set.
+    ///
+    /// A message with this flag does not trigger push and desktop
+    /// notifications, although mentions are still highlighted.
+    pub fn suppress_notifications(&mut self, suppress: bool) -> &mut Self {
+        set_flag(&mut self.0, MessageFlags::SUPPRESS_NOTIFICATIONS, suppress);
+        self
+    }
+
     /// Sets a single sticker ID to include in the message.
     ///
     /// **Note**: This will replace all existing stickers. Use
@@ -305,6 +315,15 @@ impl<'a> CreateMessage<'a> {
     }
 }
 
+/// Adds or removes a flag in the `flags` of a message builder, keeping any
+/// other flags that have been set.
+pub(super) fn set_flag(map: &mut HashMap<&'static str, Value>, flag: MessageFlags, enabled: bool) {
+    let flags = map.get("flags").map_or(0, |f| f.as_u64().expect("Message flag was not a number"));
+
+    let flags = if enabled { flags | flag.bits() } else { flags & !flag.bits() };
+    map.insert("flags", from_number(flags));
+}
+
 impl<'a> Default for CreateMessage<'a> {
     /// Creates a map for sending a [`Message`], setting [`Self::tts`] to `false` by
     /// default.
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use super::create_message::set_flag;
 use super::{CreateAllowedMentions, CreateEmbed};
 use crate::builder::CreateComponents;
 use crate::internal::prelude::*;
@@ -44,7 +45,7 @@ impl<'a> EditMessage<'a> {
     }
 
     fn _add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
-        let map = json::hashmap_to_json_map(embed.0);
+        let map = embed.into_json_map();
         let embed = Value::from(map);
 
         let embeds = self.0.entry("embeds").or_insert_with(|| Value::from(Vec::<Value>::new()));
@@ -122,12 +123,17 @@ impl<'a> EditMessage<'a> {
 
     /// Suppress or unsuppress embeds in the message, this includes those generated by Discord
     /// themselves.
+    ///
+    /// Any other flags that have been set are kept.
     pub fn suppress_embeds(&mut self, suppress: bool) -> &mut Self {
-        // `1 << 2` is defined by the API to be the SUPPRESS_EMBEDS flag.
-        // At the time of writing, the only accepted value in "flags" is `SUPPRESS_EMBEDS` for editing messages.
-        let flags = if suppress { 1 << 2 } else { 0 };
-        self.0.insert("flags", from_number(flags));
+        set_flag(&mut self.0, MessageFlags::SUPPRESS_EMBEDS, suppress);
+        self
+    }
 
+    /// Adds or removes the [`MessageFlags::SUPPRESS_NOTIFICATIONS`] flag,
+    /// keeping any other flags that have been set.
+    pub fn suppress_notifications(&mut self, suppress: bool) -> &mut Self {
+        set_flag(&mut self.0, MessageFlags::SUPPRESS_NOTIFICATIONS, suppress);
         self
     }
 
@@ -216,3 +222,23 @@ impl<'a> EditMessage<'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::EditMessage;
+    use crate::json::from_number;
+    use crate::model::channel::MessageFlags;
+
+    #[test]
+    fn flag_setters_preserve_other_flags() {
+        let mut builder = EditMessage::default();
+        builder.suppress_embeds(true).suppress_notifications(true);
+
+        let both = MessageFlags::SUPPRESS_EMBEDS | MessageFlags::SUPPRESS_NOTIFICATIONS;
+        assert_eq!(builder.0.get("flags"), Some(&from_number(both.bits())));
+
+        builder.suppress_embeds(false);
+        let expected = from_number(MessageFlags::SUPPRESS_NOTIFICATIONS.bits());
+        assert_eq!(builder.0.get("flags"), Some(&expected));
+    }
+}
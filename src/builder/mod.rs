@@ -68,7 +68,14 @@ pub use self::create_components::{
     CreateSelectMenuOption,
     CreateSelectMenuOptions,
 };
-pub use self::create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter};
+pub(crate) use self::create_embed::{embed_length, embed_text_parts};
+pub use self::create_embed::{
+    CreateEmbed,
+    CreateEmbedAuthor,
+    CreateEmbedFooter,
+    EmbedValidationError,
+    LinkPreview,
+};
 pub use self::create_interaction_response::{
     CreateAutocompleteResponse,
     CreateInteractionResponse,
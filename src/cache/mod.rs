@@ -1038,7 +1038,6 @@ mod test {
     use std::collections::HashMap;
 
     use crate::cache::{Cache, CacheUpdate, Settings};
-    use crate::json::from_number;
     use crate::model::prelude::*;
 
     #[test]
@@ -1075,7 +1074,7 @@ mod test {
                 mention_roles: vec![],
                 mention_channels: vec![],
                 mentions: vec![],
-                nonce: from_number(1),
+                nonce: Some(MessageNonce::Number(1)),
                 pinned: false,
                 reactions: vec![],
                 timestamp: datetime,
@@ -1088,6 +1087,7 @@ mod test {
                 sticker_items: vec![],
                 referenced_message: None,
                 interaction: None,
+                interaction_metadata: None,
                 components: vec![],
                 application_id: None,
                 thread: None,
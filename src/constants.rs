@@ -6,6 +6,9 @@ pub const EMBED_MAX_LENGTH: usize = 6000;
 /// The maximum number of embeds in a message.
 pub const EMBED_MAX_COUNT: usize = 10;
 
+/// The maximum number of fields in an embed.
+pub const EMBED_MAX_FIELD_COUNT: usize = 25;
+
 /// The maximum number of stickers in a message.
 pub const STICKER_MAX_COUNT: usize = 3;
 
@@ -147,3 +150,16 @@ pub mod close_codes {
     /// Disallowed gateway intents have been provided.
     pub const DISALLOWED_GATEWAY_INTENTS: u16 = 4014;
 }
+
+/// Error codes returned by Discord in the body of unsuccessful HTTP requests.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json).
+pub mod json_error_codes {
+    /// The requested message does not exist.
+    pub const UNKNOWN_MESSAGE: isize = 10008;
+    /// The requested sticker does not exist, or is not available to the
+    /// current user.
+    pub const UNKNOWN_STICKER: isize = 10060;
+    /// The maximum number of pins (50) has been reached in the channel.
+    pub const MAXIMUM_PINS_REACHED: isize = 30003;
+}
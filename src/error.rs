@@ -4,6 +4,8 @@ use std::io::Error as IoError;
 
 #[cfg(feature = "gateway")]
 use async_tungstenite::tungstenite::error::Error as TungsteniteError;
+#[cfg(feature = "image_colour")]
+use image::ImageError;
 #[cfg(feature = "http")]
 use reqwest::{header::InvalidHeaderValue, Error as ReqwestError};
 use serde_json::Error as JsonError;
@@ -99,6 +101,9 @@ pub enum Error {
     /// An error from the `tungstenite` crate.
     #[cfg(feature = "gateway")]
     Tungstenite(TungsteniteError),
+    /// An error from the `image` crate, e.g. when decoding an image failed.
+    #[cfg(feature = "image_colour")]
+    Image(ImageError),
 }
 
 #[cfg(feature = "simd-json")]
@@ -146,6 +151,13 @@ impl From<TungsteniteError> for Error {
     }
 }
 
+#[cfg(feature = "image_colour")]
+impl From<ImageError> for Error {
+    fn from(e: ImageError) -> Error {
+        Error::Image(e)
+    }
+}
+
 #[cfg(feature = "http")]
 impl From<HttpError> for Error {
     fn from(e: HttpError) -> Error {
@@ -190,6 +202,8 @@ impl fmt::Display for Error {
             Self::Http(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "gateway")]
             Self::Tungstenite(inner) => fmt::Display::fmt(&inner, f),
+            #[cfg(feature = "image_colour")]
+            Self::Image(inner) => fmt::Display::fmt(&inner, f),
         }
     }
 }
@@ -212,6 +226,8 @@ impl StdError for Error {
             Self::Http(inner) => Some(inner),
             #[cfg(feature = "gateway")]
             Self::Tungstenite(inner) => Some(inner),
+            #[cfg(feature = "image_colour")]
+            Self::Image(inner) => Some(inner),
             _ => None,
         }
     }
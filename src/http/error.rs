@@ -1,5 +1,6 @@
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
 use reqwest::header::InvalidHeaderValue;
 use reqwest::{Error as ReqwestError, Response, StatusCode, Url};
@@ -35,15 +36,27 @@ pub struct ErrorResponse {
     pub status_code: StatusCode,
     pub url: Url,
     pub error: DiscordJsonError,
+    /// How long to wait before retrying the request, as given by the
+    /// `retry-after` header of a ratelimited response.
+    pub retry_after: Option<Duration>,
 }
 
 impl ErrorResponse {
     // We need a freestanding from-function since we cannot implement an async
     // From-trait.
     pub async fn from_response(r: Response) -> Self {
+        let retry_after = r
+            .headers()
+            .get("retry-after")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<f64>().ok())
+            .filter(|secs| secs.is_finite() && *secs >= 0.0)
+            .map(Duration::from_secs_f64);
+
         ErrorResponse {
             status_code: r.status(),
             url: r.url().clone(),
+            retry_after,
             error: r.json().await.unwrap_or_else(|e| DiscordJsonError {
                 code: -1,
                 message: format!("[Serenity] Could not decode json when receiving error response from discord:, {}", e),
@@ -113,6 +126,30 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Returns how long to wait before retrying, if the error is a ratelimited
+    /// request.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::UnsuccessfulRequest(res) => res.retry_after,
+            _ => None,
+        }
+    }
+
+    /// Returns the Discord JSON error code if the error is an unsuccessful
+    /// request.
+    ///
+    /// See [`constants::json_error_codes`] for some of the known codes.
+    ///
+    /// [`constants::json_error_codes`]: crate::constants::json_error_codes
+    #[must_use]
+    pub fn discord_error_code(&self) -> Option<isize> {
+        match self {
+            Self::UnsuccessfulRequest(res) => Some(res.error.code),
+            _ => None,
+        }
+    }
 }
 
 impl From<ErrorResponse> for Error {
@@ -215,6 +252,7 @@ mod test {
             status_code: reqwest::StatusCode::from_u16(403).unwrap(),
             url: String::from("https://ferris.crab").parse().unwrap(),
             error,
+            retry_after: None,
         };
 
         assert_eq!(error_response, known);
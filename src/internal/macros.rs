@@ -43,6 +43,87 @@ macro_rules! feature_cache {
 }
 
 macro_rules! enum_number {
+    // Defines the enum itself, with an `Unknown` variant that keeps the raw
+    // value of variants not known to the library.
+    (
+        $(#[$outer:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$attr:meta])*
+                $variant:ident = $value:literal,
+            )*
+            _ => Unknown(u64),
+        }
+    ) => {
+        $(#[$outer])*
+        $vis enum $name {
+            $(
+                $(#[$attr])*
+                $variant,
+            )*
+            /// A value unknown to the library, containing the raw value
+            /// Discord sent.
+            Unknown(u64),
+        }
+
+        impl $name {
+            #[inline]
+            #[must_use]
+            pub fn num(&self) -> u64 {
+                match *self {
+                    $( Self::$variant => $value, )*
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                match value {
+                    $( $value => Self::$variant, )*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where S: serde::Serializer
+            {
+                serializer.serialize_u64(self.num())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where D: serde::Deserializer<'de>
+            {
+                struct Visitor;
+
+                impl<'de> serde::de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>)
+                        -> std::fmt::Result {
+                        formatter.write_str("positive integer")
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> std::result::Result<$name, E>
+                        where E: serde::de::Error
+                    {
+                        let kind = $name::from(value);
+                        if let $name::Unknown(_) = kind {
+                            tracing::warn!("Unknown {} value: {}", stringify!($name), value);
+                        }
+
+                        Ok(kind)
+                    }
+                }
+
+                deserializer.deserialize_u64(Visitor)
+            }
+        }
+    };
     ($name:ident { $($(#[$attr:meta])? $variant:ident $(,)? )* }) => {
         impl $name {
             #[inline]
@@ -4,6 +4,8 @@ pub mod message_component;
 pub mod modal;
 pub mod ping;
 
+use std::collections::HashMap;
+
 use serde::de::{Deserialize, Deserializer, Error as DeError};
 use serde::ser::{Serialize, Serializer};
 
@@ -13,7 +15,7 @@ use self::message_component::MessageComponentInteraction;
 use self::modal::ModalSubmitInteraction;
 use self::ping::PingInteraction;
 use crate::json::{from_value, JsonMap, Value};
-use crate::model::id::{ApplicationId, InteractionId};
+use crate::model::id::{ApplicationId, InteractionId, MessageId};
 use crate::model::user::User;
 use crate::model::Permissions;
 
@@ -253,6 +255,43 @@ pub struct MessageInteraction {
     pub user: User,
 }
 
+/// Metadata about the interaction, including the source of the interaction and
+/// relevant server and user IDs, sent in the [`Message`] object.
+///
+/// This supersedes [`MessageInteraction`], and additionally links follow-ups
+/// of message components back to the interaction that triggered them.
+///
+/// [`Message`]: crate::model::channel::Message
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-interaction-metadata-object).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MessageInteractionMetadata {
+    /// The id of the interaction.
+    pub id: InteractionId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The user who triggered the interaction.
+    pub user: User,
+    /// The ids of the installation contexts that authorized the interaction,
+    /// keyed by integration type.
+    #[serde(default)]
+    pub authorizing_integration_owners: HashMap<String, String>,
+    /// The id of the original response message.
+    ///
+    /// Only present on follow-up messages.
+    pub original_response_message_id: Option<MessageId>,
+    /// The id of the message that contained the interactive component.
+    ///
+    /// Only present on messages created from component interactions.
+    pub interacted_message_id: Option<MessageId>,
+    /// The metadata of the interaction that opened the modal.
+    ///
+    /// Only present on messages created from modal submit interactions.
+    pub triggering_interaction_metadata: Option<Box<MessageInteractionMetadata>>,
+}
+
 /// The available responses types for an interaction response.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type).
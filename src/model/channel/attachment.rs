@@ -1,5 +1,10 @@
+#[cfg(feature = "model")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
 #[cfg(feature = "model")]
 use reqwest::Client as ReqwestClient;
+#[cfg(feature = "model")]
+use url::Url;
 
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
@@ -52,6 +57,53 @@ impl Attachment {
         self.width.and_then(|width| self.height.map(|height| (width, height)))
     }
 
+    /// Returns the top-level [media type] of the attachment, such as `image`
+    /// or `video`.
+    ///
+    /// This is taken from [`Self::content_type`]. If Discord did not send one,
+    /// it is guessed from the extension of [`Self::filename`] for common
+    /// image, video and audio formats.
+    ///
+    /// [media type]: https://en.wikipedia.org/wiki/Media_type
+    #[must_use]
+    pub fn media_type(&self) -> Option<&str> {
+        if let Some(content_type) = &self.content_type {
+            return content_type.split('/').next();
+        }
+
+        let (_, extension) = self.filename.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "png" | "jpg" | "jpeg" | "gif" | "webp" | "bmp" | "avif" => Some("image"),
+            "mp4" | "webm" | "mov" | "mkv" | "avi" => Some("video"),
+            "mp3" | "ogg" | "wav" | "flac" | "m4a" | "opus" => Some("audio"),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the signed [`Self::url`] of the attachment has expired.
+    ///
+    /// Discord signs attachment URLs with an expiry time, given as a
+    /// hexadecimal unix timestamp in the `ex` query parameter. Once expired,
+    /// fresh URLs can be obtained with [`Message::refresh_attachment_urls`].
+    ///
+    /// Returns `false` if the URL is not signed.
+    ///
+    /// [`Message::refresh_attachment_urls`]: super::Message::refresh_attachment_urls
+    #[must_use]
+    pub fn is_url_expired(&self) -> bool {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+        matches!(self.url_expiry(), Some(expires_at) if expires_at <= now)
+    }
+
+    /// Parses the expiry unix timestamp out of the signed [`Self::url`].
+    fn url_expiry(&self) -> Option<u64> {
+        let url = Url::parse(&self.url).ok()?;
+        let (_, expiry) = url.query_pairs().find(|(key, _)| key == "ex")?;
+
+        u64::from_str_radix(&expiry, 16).ok()
+    }
+
     /// Downloads the attachment, returning back a vector of bytes.
     ///
     /// # Examples
@@ -137,3 +189,47 @@ impl Attachment {
         Ok(bytes.to_vec())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Attachment;
+    use crate::json::{self, json};
+
+    fn attachment(url: &str) -> Attachment {
+        json::from_value(json!({
+            "id": "1",
+            "filename": "image.png",
+            "size": 1,
+            "url": url,
+            "proxy_url": url,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn attachment_url_expiry() {
+        let base = "https://cdn.discordapp.com/attachments/1/2/image.png";
+
+        assert!(attachment(&format!("{}?ex=5f5e1000&is=5f5e0000&hm=ab", base)).is_url_expired());
+        assert!(!attachment(&format!("{}?ex=ffffffff&is=5f5e0000&hm=ab", base)).is_url_expired());
+        assert!(!attachment(base).is_url_expired());
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn attachment_media_type() {
+        let mut attachment = attachment("https://cdn.discordapp.com/image.png");
+        assert_eq!(attachment.media_type(), Some("image"));
+
+        attachment.filename = "clip.MP4".to_string();
+        assert_eq!(attachment.media_type(), Some("video"));
+
+        attachment.content_type = Some("audio/ogg".to_string());
+        assert_eq!(attachment.media_type(), Some("audio"));
+
+        attachment.content_type = None;
+        attachment.filename = "notes".to_string();
+        assert_eq!(attachment.media_type(), None);
+    }
+}
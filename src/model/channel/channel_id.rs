@@ -1,10 +1,12 @@
 #[cfg(feature = "model")]
+use std::collections::HashSet;
+#[cfg(feature = "model")]
 use std::fmt::Write as _;
 #[cfg(feature = "model")]
 use std::sync::Arc;
 
 #[cfg(feature = "model")]
-use futures::stream::Stream;
+use futures::stream::{Stream, StreamExt};
 
 #[cfg(feature = "model")]
 use crate::builder::{
@@ -39,6 +41,14 @@ use crate::model::prelude::*;
 #[cfg(feature = "model")]
 use crate::utils::encode_image;
 
+/// The amount of reactions created at the same time by [`ChannelId::react_all`].
+#[cfg(feature = "model")]
+const REACT_ALL_CONCURRENCY: usize = 3;
+
+/// The maximum age of messages that can be bulk deleted, in seconds.
+#[cfg(feature = "model")]
+const BULK_DELETE_MAX_AGE: i64 = 14 * 24 * 60 * 60;
+
 #[cfg(feature = "model")]
 impl ChannelId {
     /// Broadcasts that the current user is typing to a channel for the next 5
@@ -152,6 +162,38 @@ impl ChannelId {
         http.as_ref().create_reaction(self.0, message_id.into().0, &reaction_type.into()).await
     }
 
+    /// Reacts to each of the given messages with the same [`ReactionType`].
+    ///
+    /// Up to 3 reactions are created at the same time. The result of each
+    /// reaction is returned alongside the Id of its message, in the same order
+    /// as the given message Ids.
+    ///
+    /// Unlike [`Message::react_all`], which adds many reactions to one message
+    /// one after the other and stops at the first error, this adds one reaction
+    /// to many messages and attempts every message regardless of failures.
+    ///
+    /// Requires the [Add Reactions] permission, _if_ the current user is the
+    /// first user to perform a react with a certain emoji.
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    pub async fn react_all(
+        self,
+        http: impl AsRef<Http>,
+        message_ids: &[MessageId],
+        reaction_type: impl Into<ReactionType>,
+    ) -> Vec<(MessageId, Result<()>)> {
+        let http = http.as_ref();
+        let reaction_type = reaction_type.into();
+        let reaction_type = &reaction_type;
+
+        futures::stream::iter(message_ids.iter().map(|&message_id| async move {
+            (message_id, http.create_reaction(self.0, message_id.0, reaction_type).await)
+        }))
+        .buffered(REACT_ALL_CONCURRENCY)
+        .collect()
+        .await
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     ///
     /// **Note**: Requires the [Manage Channels] permission.
@@ -229,6 +271,58 @@ impl ChannelId {
         }
     }
 
+    /// Deletes any amount of messages by their Ids, taking care of Discord's
+    /// bulk deletion limits.
+    ///
+    /// Messages are bulk deleted in batches of up to 100. Messages older than
+    /// two weeks can't be bulk deleted, so they are deleted one by one
+    /// instead, which is subject to a much stricter rate limit. Duplicate Ids
+    /// are ignored.
+    ///
+    /// A failing request does not stop the remaining messages from being
+    /// deleted; all failures are collected in the returned
+    /// [`DeleteMessagesOutcome`] instead.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission, unless all
+    /// messages were sent by the current user.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn delete_messages_chunked(
+        self,
+        http: impl AsRef<Http>,
+        message_ids: impl IntoIterator<Item = impl Into<MessageId>>,
+    ) -> DeleteMessagesOutcome {
+        let http = http.as_ref();
+        // Leave a minute of leeway, so that messages don't become too old for
+        // bulk deletion while the requests are made.
+        let cutoff = Timestamp::now().unix_timestamp() - BULK_DELETE_MAX_AGE + 60;
+
+        let mut seen = HashSet::new();
+        let (recent, old): (Vec<MessageId>, Vec<MessageId>) = message_ids
+            .into_iter()
+            .map(Into::into)
+            .filter(|id| seen.insert(*id))
+            .partition(|id| id.created_at().unix_timestamp() > cutoff);
+
+        let mut outcome = DeleteMessagesOutcome::default();
+
+        for batch in recent.chunks(100) {
+            match self.delete_messages(http, batch).await {
+                Ok(()) => outcome.deleted += batch.len(),
+                Err(why) => outcome.failures.push((batch.to_vec(), why)),
+            }
+        }
+
+        for id in old {
+            match self.delete_message(http, id).await {
+                Ok(()) => outcome.deleted += 1,
+                Err(why) => outcome.failures.push((vec![id], why)),
+            }
+        }
+
+        outcome
+    }
+
     /// Deletes all permission overrides in the channel from a member or role.
     ///
     /// **Note**: Requires the [Manage Channel] permission.
@@ -357,6 +451,9 @@ impl ChannelId {
     /// is over the [`the limit`], containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::StickerAmount`] if more than 3 sticker ids were
+    /// provided, mirroring the validation done when sending a message.
+    ///
     /// [`the limit`]: crate::builder::EditMessage::content
     #[inline]
     pub async fn edit_message<'a, F>(
@@ -371,14 +468,10 @@ impl ChannelId {
         let mut msg = EditMessage::default();
         f(&mut msg);
 
-        if let Some(Value::String(content)) = msg.0.get("content") {
-            if let Some(length_over) = Message::overflow_length(content) {
-                return Err(Error::Model(ModelError::MessageTooLong(length_over)));
-            }
-        }
-
         let map = json::hashmap_to_json_map(msg.0);
 
+        Message::check_lengths(&map)?;
+
         http.as_ref()
             .edit_message_and_attachments(self.0, message_id.into().0, &Value::from(map), msg.1)
             .await
@@ -1221,6 +1314,27 @@ impl<'a> From<&'a GuildChannel> for ChannelId {
     }
 }
 
+/// The outcome of [`ChannelId::delete_messages_chunked`].
+#[cfg(feature = "model")]
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct DeleteMessagesOutcome {
+    /// The number of messages that were deleted.
+    pub deleted: usize,
+    /// The messages that could not be deleted, grouped by the request that
+    /// failed, along with the error of that request.
+    pub failures: Vec<(Vec<MessageId>, Error)>,
+}
+
+#[cfg(feature = "model")]
+impl DeleteMessagesOutcome {
+    /// True if all messages were deleted.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
 /// A helper class returned by [`ChannelId::messages_iter`]
 #[derive(Clone, Debug)]
 #[cfg(feature = "model")]
@@ -1,9 +1,13 @@
 #[cfg(feature = "model")]
-use crate::builder::CreateEmbed;
+use crate::builder::{embed_length, embed_text_parts, CreateEmbed};
+#[cfg(feature = "model")]
+use crate::constants;
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::json;
+#[cfg(feature = "model")]
+use crate::model::{ModelError, Timestamp};
 #[cfg(feature = "utils")]
 use crate::utils::Colour;
 
@@ -14,7 +18,7 @@ use crate::utils::Colour;
 /// a webhook.
 ///
 /// **Note**: Maximum amount of characters you can put is 256 in a field name,
-/// 1024 in a field value, and 2048 in a description.
+/// 1024 in a field value, and 4096 in a description.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#embed-object).
 ///
@@ -34,7 +38,7 @@ pub struct Embed {
     pub colour: u32,
     /// The description of the embed.
     ///
-    /// The maximum value for this field is 2048 unicode codepoints.
+    /// The maximum value for this field is 4096 unicode codepoints.
     pub description: Option<String>,
     /// The array of fields.
     ///
@@ -98,10 +102,305 @@ impl Embed {
     {
         let mut create_embed = CreateEmbed::default();
         f(&mut create_embed);
-        let map = json::hashmap_to_json_map(create_embed.0);
+        let map = create_embed.into_json_map();
 
         Value::from(map)
     }
+
+    /// Returns the first field with the given name, if any.
+    ///
+    /// The name is compared exactly, including case.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&EmbedField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the first field with the given name, if any, ignoring ASCII
+    /// case.
+    ///
+    /// This is useful for embeds from other bots with inconsistent casing.
+    #[must_use]
+    pub fn field_ignore_case(&self, name: &str) -> Option<&EmbedField> {
+        self.fields.iter().find(|field| field.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the value of the first field with the given name, if any.
+    ///
+    /// This is useful for reading back state previously stored in an embed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::json::{json, prelude::from_value};
+    /// use serenity::model::channel::Embed;
+    ///
+    /// # let embed: Embed = from_value(json!({
+    /// #     "fields": [{"name": "Status", "value": "open", "inline": true}],
+    /// # })).unwrap();
+    /// assert_eq!(embed.field_value("Status"), Some("open"));
+    /// assert_eq!(embed.field_value("Owner"), None);
+    /// ```
+    #[must_use]
+    pub fn field_value(&self, name: &str) -> Option<&str> {
+        self.field(name).map(|field| field.value.as_str())
+    }
+
+    /// Flattens the embed into human-readable text, e.g. for transcripts or
+    /// text-to-speech.
+    ///
+    /// The title, description, each field as `name: value` and the footer text
+    /// are put on their own lines, in that order. Missing or empty sections
+    /// are skipped, and whether a field is inline does not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::json::{json, prelude::from_value};
+    /// use serenity::model::channel::Embed;
+    ///
+    /// # let embed: Embed = from_value(json!({
+    /// #     "title": "Ticket #4",
+    /// #     "fields": [{"name": "Status", "value": "open", "inline": true}],
+    /// #     "footer": {"text": "Opened by Alice"},
+    /// # })).unwrap();
+    /// assert_eq!(embed.to_plaintext(), "Ticket #4\nStatus: open\nOpened by Alice");
+    /// ```
+    #[must_use]
+    pub fn to_plaintext(&self) -> String {
+        let mut lines = Vec::with_capacity(3 + self.fields.len());
+
+        lines.extend(self.title.clone());
+        lines.extend(self.description.clone());
+        lines.extend(self.fields.iter().map(|field| format!("{}: {}", field.name, field.value)));
+        lines.extend(self.footer.as_ref().map(|footer| footer.text.clone()));
+
+        lines.retain(|line| !line.trim().is_empty());
+        lines.join("\n")
+    }
+
+    /// Returns whether Discord will render the [`Self::image`] of this embed.
+    ///
+    /// An image is only rendered if its URL uses the `http`, `https` or
+    /// `attachment` scheme; images with any other URL are silently dropped.
+    ///
+    /// **Note**: When sending multiple embeds, Discord only groups their
+    /// images into a gallery if all of the embeds have the same [`Self::url`].
+    /// Use [`Self::images_without_url`] to find the embeds that break this.
+    #[must_use]
+    pub fn will_render_image(&self) -> bool {
+        let url = match &self.image {
+            Some(image) => &image.url,
+            None => return false,
+        };
+
+        ["http://", "https://", "attachment://"]
+            .iter()
+            .any(|scheme| url.starts_with(scheme) && url.len() > scheme.len())
+    }
+
+    /// Returns the indices of the embeds that have an image but no
+    /// [`Self::url`], if there is more than one embed.
+    ///
+    /// When a message has multiple embeds, Discord only groups their images
+    /// into a gallery if the embeds share the same URL. The images of the
+    /// returned embeds are dropped from the gallery.
+    ///
+    /// See [`CreateEmbed::images_without_url`] for checking embeds before
+    /// sending them.
+    #[must_use]
+    pub fn images_without_url(embeds: &[Embed]) -> Vec<usize> {
+        let embeds: Vec<_> =
+            embeds.iter().map(|embed| (embed.image.is_some(), embed.url.is_some())).collect();
+
+        images_without_url(&embeds)
+    }
+
+    /// Returns the source URLs of the [`Self::image`], [`Self::thumbnail`] and
+    /// [`Self::video`] of the embed, in that order, skipping those that are
+    /// not set.
+    ///
+    /// The original URLs are returned rather than Discord's proxied ones.
+    #[must_use]
+    pub fn media_urls(&self) -> Vec<&str> {
+        let image = self.image.as_ref().map(|image| image.url.as_str());
+        let thumbnail = self.thumbnail.as_ref().map(|thumbnail| thumbnail.url.as_str());
+        let video = self.video.as_ref().map(|video| video.url.as_str());
+
+        [image, thumbnail, video].iter().flatten().copied().collect()
+    }
+
+    /// Checks whether the embed is within Discord's limits.
+    ///
+    /// Embeds received from webhooks or other bots are deserialized as-is, so
+    /// this can be used to detect embeds that Discord would reject when
+    /// re-sending them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedFieldAmount`] if the embed has more than 25
+    /// fields.
+    ///
+    /// Returns a [`ModelError::EmbedFieldTooLong`] if a part of the embed is
+    /// over its own limit, see [`EmbedFieldKind::limit`].
+    ///
+    /// Returns a [`ModelError::EmbedTooLarge`] if its textual content is over
+    /// 6000 unicode code points, containing the number of code points over the
+    /// limit.
+    pub fn validate(&self) -> Result<()> {
+        if self.fields.len() > constants::EMBED_MAX_FIELD_COUNT {
+            return Err(Error::Model(ModelError::EmbedFieldAmount));
+        }
+
+        let embed = self.to_json();
+        let parts = embed_text_parts(|key| embed.get(key));
+        check_text_parts(&parts)?;
+
+        let total: usize = parts.iter().map(|(_, text)| text.chars().count()).sum();
+        if total > constants::EMBED_MAX_LENGTH {
+            let overflow = total - constants::EMBED_MAX_LENGTH;
+            return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the embed is within Discord's limits.
+    ///
+    /// See [`Self::validate`] for the checks performed.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Parses the embed's [`Self::timestamp`](#structfield.timestamp) into a
+    /// [`Timestamp`].
+    ///
+    /// Returns [`None`] if the embed has no timestamp or it is not a valid
+    /// RFC 3339 date and time string.
+    #[must_use]
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        Timestamp::parse(self.timestamp.as_deref()?).ok()
+    }
+
+    /// Formats the embed's [`Self::timestamp()`] as Discord's relative time
+    /// markdown, e.g. `<t:1462015105:R>`, which clients render as "5 minutes
+    /// ago" and keep up to date.
+    ///
+    /// Returns [`None`] if the embed has no timestamp or it is not a valid
+    /// RFC 3339 date and time string.
+    #[must_use]
+    pub fn timestamp_relative_markdown(&self) -> Option<String> {
+        let timestamp = self.timestamp()?;
+
+        Some(format!("<t:{}:R>", timestamp.unix_timestamp()))
+    }
+
+    /// Returns the number of unicode code points Discord counts towards the
+    /// maximum textual length of an embed, which is 6000.
+    ///
+    /// This is the combined length of the title, description, author name,
+    /// footer text and the names and values of all fields.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let embed = self.to_json();
+
+        embed_length(|key| embed.get(key))
+    }
+
+    /// Serializes the embed, so that its textual parts are collected the same
+    /// way as those of a [`CreateEmbed`].
+    #[allow(clippy::unwrap_used)] // allowing unwrap here because serializing an Embed should never error
+    fn to_json(&self) -> Value {
+        json::to_value(self).unwrap()
+    }
+
+    /// Returns whether the embed has no text that counts towards its maximum
+    /// length, see [`Self::len`].
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A textual part of an embed that has its own maximum length.
+///
+/// Returned as part of a [`ModelError::EmbedFieldTooLong`].
+///
+/// [`ModelError::EmbedFieldTooLong`]: crate::model::ModelError::EmbedFieldTooLong
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedFieldKind {
+    /// The title of the embed.
+    Title,
+    /// The description of the embed.
+    Description,
+    /// The name of the embed's author.
+    AuthorName,
+    /// The text of the embed's footer.
+    FooterText,
+    /// The name of the field at the given index.
+    FieldName(usize),
+    /// The value of the field at the given index.
+    FieldValue(usize),
+}
+
+impl EmbedFieldKind {
+    /// Returns the maximum length of this part of an embed, in unicode code
+    /// points.
+    #[must_use]
+    pub fn limit(self) -> usize {
+        match self {
+            Self::Title | Self::AuthorName | Self::FieldName(_) => 256,
+            Self::Description => 4096,
+            Self::FooterText => 2048,
+            Self::FieldValue(_) => 1024,
+        }
+    }
+}
+
+/// Returns the indices of the image-bearing embeds without a URL, given
+/// whether each embed has an image and a URL, in that order.
+///
+/// A single embed always renders its image, so nothing is returned for it.
+#[cfg(feature = "model")]
+pub(crate) fn images_without_url(embeds: &[(bool, bool)]) -> Vec<usize> {
+    if embeds.len() < 2 {
+        return Vec::new();
+    }
+
+    embeds
+        .iter()
+        .enumerate()
+        .filter(|(_, &(has_image, has_url))| has_image && !has_url)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns each textual part of an embed that is over its own maximum length,
+/// along with its length and limit, in unicode code points.
+pub(crate) fn text_part_violations(
+    parts: &[(EmbedFieldKind, &str)],
+) -> Vec<(EmbedFieldKind, usize, usize)> {
+    parts
+        .iter()
+        .map(|&(field, text)| (field, text.chars().count(), field.limit()))
+        .filter(|&(_, len, limit)| len > limit)
+        .collect()
+}
+
+/// Checks each textual part of an embed against its own maximum length.
+#[cfg(feature = "model")]
+pub(crate) fn check_text_parts(parts: &[(EmbedFieldKind, &str)]) -> Result<()> {
+    match text_part_violations(parts).first() {
+        Some(&(field, len, limit)) => Err(Error::Model(ModelError::EmbedFieldTooLong {
+            field,
+            len,
+            limit,
+        })),
+        None => Ok(()),
+    }
 }
 
 /// An author object in an embed.
@@ -130,7 +429,7 @@ pub struct EmbedAuthor {
 pub struct EmbedField {
     /// The name of the field.
     ///
-    /// The maximum length of this field is 512 unicode codepoints.
+    /// The maximum length of this field is 256 unicode codepoints.
     pub name: String,
     /// The value of the field.
     ///
@@ -161,6 +460,29 @@ impl EmbedField {
             inline,
         }
     }
+
+    /// Creates a new embed field like [`Self::new`], cutting the name and
+    /// value down to their maximum lengths of 256 and 1024 unicode code
+    /// points respectively.
+    pub fn new_truncated<T, U>(name: T, value: U, inline: bool) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let name = truncate_chars(name.into(), EmbedFieldKind::FieldName(0).limit());
+        let value = truncate_chars(value.into(), EmbedFieldKind::FieldValue(0).limit());
+
+        Self::_new(name, value, inline)
+    }
+}
+
+/// Shortens a string to at most `max` unicode code points.
+fn truncate_chars(mut text: String, max: usize) -> String {
+    if let Some((index, _)) = text.char_indices().nth(max) {
+        text.truncate(index);
+    }
+
+    text
 }
 
 /// Footer information for an embed.
@@ -242,3 +564,126 @@ pub struct EmbedVideo {
     /// The width of the video in pixels.
     pub width: Option<u64>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::Embed;
+    use crate::json::{self, json};
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn embed_with_too_many_fields() {
+        let fields: Vec<_> = (0..30)
+            .map(|i| json!({"name": format!("name {}", i), "value": "value", "inline": false}))
+            .collect();
+
+        let embed: Embed = json::from_value(json!({"fields": fields})).unwrap();
+
+        assert_eq!(embed.fields.len(), 30);
+        assert!(!embed.is_valid());
+        assert!(matches!(
+            embed.validate(),
+            Err(crate::Error::Model(crate::model::ModelError::EmbedFieldAmount))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn embed_with_too_long_field() {
+        use super::EmbedFieldKind;
+
+        let embed: Embed = json::from_value(json!({
+            "title": "title",
+            "fields": [
+                {"name": "a", "value": "b", "inline": false},
+                {"name": "c", "value": "d".repeat(1025), "inline": false},
+            ],
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            embed.validate(),
+            Err(crate::Error::Model(crate::model::ModelError::EmbedFieldTooLong {
+                field: EmbedFieldKind::FieldValue(1),
+                len: 1025,
+                limit: 1024,
+            }))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn field_lookup() {
+        let embed: Embed = json::from_value(json!({
+            "fields": [
+                {"name": "Status", "value": "open", "inline": true},
+                {"name": "status", "value": "closed", "inline": true},
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(embed.field_value("status"), Some("closed"));
+        assert_eq!(embed.field_ignore_case("STATUS").map(|f| f.value.as_str()), Some("open"));
+        assert!(embed.field_ignore_case("owner").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn media_urls() {
+        let embed: Embed = json::from_value(json!({
+            "image": {"url": "https://example.com/a.png", "proxy_url": "https://proxy/a.png"},
+            "video": {"url": "https://example.com/b.mp4"},
+        }))
+        .unwrap();
+
+        assert_eq!(embed.media_urls(), ["https://example.com/a.png", "https://example.com/b.mp4"]);
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn images_without_url() {
+        let with_url: Embed = json::from_value(json!({
+            "url": "https://example.com",
+            "image": {"url": "https://example.com/a.png"},
+        }))
+        .unwrap();
+        let without_url: Embed =
+            json::from_value(json!({"image": {"url": "https://example.com/b.png"}})).unwrap();
+        let text: Embed = json::from_value(json!({"title": "text"})).unwrap();
+
+        assert!(Embed::images_without_url(std::slice::from_ref(&without_url)).is_empty());
+        assert_eq!(Embed::images_without_url(&[with_url, text, without_url]), [2]);
+    }
+
+    #[test]
+    fn field_new_truncated() {
+        use super::EmbedField;
+
+        let field = EmbedField::new_truncated("\u{e9}".repeat(300), "v".repeat(1024), true);
+        assert_eq!(field.name.chars().count(), 256);
+        assert_eq!(field.value.len(), 1024);
+        assert!(field.inline);
+
+        let field = EmbedField::new_truncated("name", "value", false);
+        assert_eq!((field.name.as_str(), field.value.as_str()), ("name", "value"));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn to_plaintext() {
+        let embed: Embed = json::from_value(json!({
+            "title": "Poll",
+            "description": "",
+            "fields": [
+                {"name": "Yes", "value": "3", "inline": true},
+                {"name": "No", "value": "1", "inline": false},
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(embed.to_plaintext(), "Poll\nYes: 3\nNo: 1");
+
+        let embed: Embed = json::from_value(json!({})).unwrap();
+        assert_eq!(embed.to_plaintext(), "");
+    }
+}
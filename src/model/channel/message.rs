@@ -1,12 +1,36 @@
 //! Models relating to Discord channels.
 
+#[cfg(feature = "model")]
+use std::cmp::Ordering;
+#[cfg(feature = "model")]
+use std::collections::HashMap;
+#[cfg(feature = "model")]
+use std::convert::TryFrom;
+#[cfg(feature = "model")]
+use std::error::Error as StdError;
+use std::fmt;
 #[cfg(feature = "model")]
 use std::fmt::Display;
-#[cfg(all(feature = "cache", feature = "model"))]
-use std::fmt::Write;
+#[cfg(feature = "model")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "model")]
+use futures::stream::{Stream, StreamExt, TryStreamExt};
+#[cfg(feature = "model")]
+use tokio::task::JoinHandle;
+#[cfg(feature = "model")]
+use tokio::time::{sleep, Duration};
 
+#[cfg(feature = "model")]
+use crate::builder::embed_text_parts;
 #[cfg(all(feature = "model", feature = "utils"))]
-use crate::builder::{CreateEmbed, EditMessage};
+use crate::builder::{
+    CreateComponents,
+    CreateEmbed,
+    CreateMessage,
+    EditMessage,
+    EditWebhookMessage,
+};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "collector")]
@@ -21,16 +45,25 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http};
+use crate::http::{CacheHttp, Http, StatusCode};
+#[cfg(feature = "model")]
+use crate::internal::tokio::spawn_named;
 #[cfg(feature = "model")]
 use crate::json;
 use crate::json::prelude::*;
 use crate::model::application::component::ActionRow;
-use crate::model::application::interaction::MessageInteraction;
+use crate::model::application::interaction::{MessageInteraction, MessageInteractionMetadata};
+#[cfg(feature = "model")]
+use crate::model::channel::embed::check_text_parts;
+#[cfg(feature = "model")]
+use crate::model::guild::automod::AutoModActionInfo;
 use crate::model::prelude::*;
+use crate::model::utils::{is_false, is_zero};
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::utils::ContentSafeOptions;
 #[cfg(feature = "model")]
 use crate::{
-    constants,
+    constants::{self, json_error_codes},
     model::{
         id::{ApplicationId, ChannelId, GuildId, MessageId},
         sticker::StickerItem,
@@ -58,6 +91,7 @@ pub struct Message {
     /// Initial message creation timestamp, calculated from its Id.
     pub timestamp: Timestamp,
     /// The timestamp of the last time the message was updated, if it was.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_timestamp: Option<Timestamp>,
     /// Indicator of whether the command is to be played back via
     /// text-to-speech.
@@ -94,35 +128,52 @@ pub struct Message {
     /// Array of reactions performed on the message.
     #[serde(default)]
     pub reactions: Vec<MessageReaction>,
-    /// Non-repeating number used for ensuring message order.
-    #[serde(default)]
-    pub nonce: Value,
+    /// Non-repeating value used for ensuring message order, or for matching
+    /// a sent message with the one echoed back by Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<MessageNonce>,
     /// Indicator of whether the message is pinned.
     pub pinned: bool,
     /// The Id of the webhook that sent this message, if one did.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub webhook_id: Option<WebhookId>,
     /// Indicator of the type of message this is, i.e. whether it is a regular
     /// message or a system message.
     #[serde(rename = "type")]
     pub kind: MessageType,
     /// Sent with Rich Presence-related chat embeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub activity: Option<MessageActivity>,
     /// Sent with Rich Presence-related chat embeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub application: Option<MessageApplication>,
     /// If the message is an Interaction or application-owned webhook, this is the id of the
     /// application.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<ApplicationId>,
     /// Reference data sent with crossposted messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message_reference: Option<MessageReference>,
     /// Bit flags describing extra features of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<MessageFlags>,
     /// The message that was replied to using this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub referenced_message: Option<Box<Message>>, // Boxed to avoid recursion
     /// Sent if the message is a response to an [`Interaction`].
     ///
     /// [`Interaction`]: crate::model::application::interaction::Interaction
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub interaction: Option<MessageInteraction>,
+    /// Sent if the message is sent as a result of an [`Interaction`].
+    ///
+    /// This supersedes [`Self::interaction`], which Discord has deprecated.
+    ///
+    /// [`Interaction`]: crate::model::application::interaction::Interaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interaction_metadata: Option<MessageInteractionMetadata>,
     /// The thread that was started from this message, includes thread member object.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread: Option<GuildChannel>,
     /// The components of this message
     #[serde(default)]
@@ -133,12 +184,33 @@ pub struct Message {
     // Field omitted: stickers (it's deprecated by Discord)
     /// The Id of the [`Guild`] that the message was sent in. This value will
     /// only be present if this message was received over the gateway.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<GuildId>,
     /// A partial amount of data about the user's member data, if this message
     /// was sent in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<PartialMember>,
 }
 
+/// The amount of reactions whose users are fetched at the same time by
+/// [`Message::all_reaction_users`] when no concurrency is given.
+#[cfg(feature = "model")]
+const DEFAULT_REACTION_USERS_CONCURRENCY: usize = 3;
+
+/// The maximum amount of users Discord returns per reaction users request.
+#[cfg(feature = "model")]
+const REACTION_USERS_PAGE_SIZE: u8 = 100;
+
+/// The state of the stream returned by [`Message::reaction_users_iter`].
+#[cfg(feature = "model")]
+struct ReactionUsersState<H> {
+    http: H,
+    reaction_type: ReactionType,
+    buffer: Vec<User>,
+    after: Option<u64>,
+    exhausted: bool,
+}
+
 #[cfg(feature = "model")]
 impl Message {
     /// Crossposts this message.
@@ -186,6 +258,47 @@ impl Message {
         self.channel_id.crosspost(cache_http.http(), self.id.0).await
     }
 
+    /// Publishes the message like [`Self::crosspost`], retrying up to
+    /// `max_retries` times if the request is ratelimited.
+    ///
+    /// Discord strictly limits how often messages can be published in a news
+    /// channel. Before each retry, this waits for as long as Discord asks to,
+    /// or one second if it did not say.
+    ///
+    /// **Note**: By default, [`Http`] already waits for and retries ratelimited
+    /// requests on its own, so this only makes a difference if the ratelimiter
+    /// is disabled with [`HttpBuilder::ratelimiter_disabled`].
+    ///
+    /// **Note**: Only available on news channels.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::crosspost`]. Errors other than
+    /// ratelimits, such as [`ModelError::MessageAlreadyCrossposted`] and
+    /// [`ModelError::CannotCrosspostMessage`], are returned without retrying.
+    ///
+    /// [`HttpBuilder::ratelimiter_disabled`]: crate::http::HttpBuilder::ratelimiter_disabled
+    pub async fn crosspost_with_retries(
+        &self,
+        cache_http: impl CacheHttp,
+        max_retries: u8,
+    ) -> Result<Message> {
+        let mut retries = 0;
+
+        loop {
+            match self.crosspost(&cache_http).await {
+                Err(Error::Http(why))
+                    if retries < max_retries
+                        && why.status_code() == Some(StatusCode::TOO_MANY_REQUESTS) =>
+                {
+                    retries += 1;
+                    sleep(why.retry_after().unwrap_or_else(|| Duration::from_secs(1))).await;
+                },
+                result => return result,
+            }
+        }
+    }
+
     /// First attempts to find a [`Channel`] by its Id in the cache,
     /// upon failure requests it via the REST API.
     ///
@@ -200,6 +313,175 @@ impl Message {
         self.channel_id.to_channel(cache_http).await
     }
 
+    /// Gets the message this message references, such as the one it replies
+    /// to.
+    ///
+    /// Unlike [`Self::referenced_message`], this also works when Discord did
+    /// not include the referenced message: it is then looked up in the cache,
+    /// and requested via the REST API upon failure, using
+    /// [`Self::message_reference`].
+    ///
+    /// Returns `Ok(None)` if the message does not reference another message.
+    ///
+    /// **Note**: Requires the [Read Message History] permission if the
+    /// message has to be requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the referenced message was deleted, or if the
+    /// current user lacks permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn referenced_message_full(
+        &self,
+        cache_http: impl CacheHttp,
+    ) -> Result<Option<Message>> {
+        if let Some(message) = &self.referenced_message {
+            return Ok(Some(Message::clone(message)));
+        }
+
+        let (channel_id, message_id) = match &self.message_reference {
+            Some(MessageReference {
+                channel_id,
+                message_id: Some(message_id),
+                ..
+            }) => (*channel_id, *message_id),
+            _ => return Ok(None),
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(message) = cache.message(channel_id, message_id) {
+                    return Ok(Some(message));
+                }
+            }
+        }
+
+        channel_id.message(cache_http.http(), message_id).await.map(Some)
+    }
+
+    /// Retrieves the original message this message was crossposted from, if
+    /// it has the [`MessageFlags::IS_CROSSPOST`] flag, i.e. was published in
+    /// an announcement channel followed by this channel.
+    ///
+    /// The original message is looked up like [`Self::referenced_message_full`]
+    /// does. Returns `Ok(None)` without making a request if the message is
+    /// not a crosspost or has the [`MessageFlags::SOURCE_MESSAGE_DELETED`]
+    /// flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to read
+    /// the source channel, or the original message could not be found.
+    pub async fn crosspost_source(&self, cache_http: impl CacheHttp) -> Result<Option<Message>> {
+        if !self.flags_contains(MessageFlags::IS_CROSSPOST)
+            || self.flags_contains(MessageFlags::SOURCE_MESSAGE_DELETED)
+        {
+            return Ok(None);
+        }
+
+        self.referenced_message_full(cache_http).await
+    }
+
+    /// Resolves the [`Self::sticker_items`] of the message into full
+    /// [`Sticker`]s, in the same order.
+    ///
+    /// Guild stickers are first looked up in the cache, and requested via the
+    /// REST API upon failure. Stickers that Discord reports as unknown, such as
+    /// those of a guild the current user is not in, are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a sticker could not be requested for any
+    /// other reason.
+    pub async fn stickers(&self, cache_http: impl CacheHttp) -> Result<Vec<Sticker>> {
+        let mut stickers = Vec::with_capacity(self.sticker_items.len());
+
+        for item in &self.sticker_items {
+            #[cfg(feature = "cache")]
+            {
+                if let (Some(cache), Some(guild_id)) = (cache_http.cache(), self.guild_id) {
+                    let cached = cache.guild_field(guild_id, |g| g.stickers.get(&item.id).cloned());
+
+                    if let Some(sticker) = cached.flatten() {
+                        stickers.push(sticker);
+                        continue;
+                    }
+                }
+            }
+
+            match item.to_sticker(cache_http.http()).await {
+                Ok(sticker) => stickers.push(sticker),
+                Err(Error::Http(why))
+                    if why.discord_error_code() == Some(json_error_codes::UNKNOWN_STICKER) => {},
+                Err(why) => return Err(why),
+            }
+        }
+
+        Ok(stickers)
+    }
+
+    /// Returns the Id of the thread started from this message, if any.
+    ///
+    /// Threads started from a message share its Id, so this also works when
+    /// Discord did not include the [`Self::thread`] itself but flagged the
+    /// message with [`MessageFlags::HAS_THREAD`].
+    #[must_use]
+    pub fn thread_id(&self) -> Option<ChannelId> {
+        match &self.thread {
+            Some(thread) => Some(thread.id),
+            None if self.has_thread() => Some(ChannelId(self.id.0)),
+            None => None,
+        }
+    }
+
+    /// True if a thread was started from this message, according to either
+    /// [`Self::thread`] or [`MessageFlags::HAS_THREAD`].
+    #[must_use]
+    pub fn has_thread(&self) -> bool {
+        self.thread.is_some() || self.flags_contains(MessageFlags::HAS_THREAD)
+    }
+
+    /// Starts a public thread named `name` from this message.
+    ///
+    /// Use [`ChannelId::create_public_thread`] to set other properties of the
+    /// thread, such as its auto archive duration.
+    ///
+    /// **Note**: Requires the [Create Public Threads] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns [`Error::Http`] if a thread was already started from this
+    /// message, or if the message is not in a guild text or news channel.
+    ///
+    /// [Create Public Threads]: Permissions::CREATE_PUBLIC_THREADS
+    pub async fn start_thread(
+        &self,
+        cache_http: impl CacheHttp,
+        name: &str,
+    ) -> Result<GuildChannel> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if self.guild_id.is_some() {
+                    utils::user_has_perms_cache(
+                        cache,
+                        self.channel_id,
+                        self.guild_id,
+                        Permissions::CREATE_PUBLIC_THREADS,
+                    )?;
+                }
+            }
+        }
+
+        self.channel_id.create_public_thread(cache_http.http(), self.id, |t| t.name(name)).await
+    }
+
     /// A util function for determining whether this message was sent by someone else, or the
     /// bot.
     #[cfg(feature = "cache")]
@@ -218,26 +500,121 @@ impl Message {
     /// [`ModelError::InvalidPermissions`] if the current user does not have
     /// the required permissions.
     ///
+    /// Returns a [`ModelError::MessageDeleted`] if Discord reports the message
+    /// as unknown, for instance because it was already deleted. Idempotent
+    /// cleanup code may treat this as success.
+    ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if self.author.id != cache.current_user_id() {
-                    if self.is_private() {
-                        return Err(Error::Model(ModelError::NotAuthor));
-                    }
-                    utils::user_has_perms_cache(
-                        cache,
-                        self.channel_id,
-                        self.guild_id,
-                        Permissions::MANAGE_MESSAGES,
-                    )?;
-                }
+                self.check_delete_permissions(cache)?;
+            }
+        }
+
+        self.send_delete(cache_http.http()).await
+    }
+
+    /// Deletes the message like [`Self::delete`], invoking `on_delete` with
+    /// the complete message right before the delete request is sent.
+    ///
+    /// This allows recording the content for audit purposes, as Discord does
+    /// not return it once the message is deleted. The callback is only invoked
+    /// if the permission checks pass.
+    ///
+    /// **Note**: The logged in user must either be the author of the message or
+    /// have the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::delete`].
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn delete_logged<F>(&self, cache_http: impl CacheHttp, on_delete: F) -> Result<()>
+    where
+        F: FnOnce(&Message),
+    {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                self.check_delete_permissions(cache)?;
+            }
+        }
+
+        on_delete(self);
+
+        self.send_delete(cache_http.http()).await
+    }
+
+    /// Spawns a task that deletes the message like [`Self::delete`] once
+    /// `delay` has passed, e.g. to clean up a short-lived confirmation.
+    ///
+    /// The returned handle resolves to the result of the deletion, and the
+    /// deletion can be cancelled by [aborting] it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use std::time::Duration;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Message;
+    /// # async fn run(http: Arc<Http>, message: &Message) -> serenity::Result<()> {
+    /// let confirmation = message.reply(&http, "Saved!").await?;
+    /// confirmation.delete_after(http, Duration::from_secs(5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Note**: The logged in user must either be the author of the message or
+    /// have the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// The handle resolves to the same errors as [`Self::delete`], with the
+    /// permissions checked after the delay.
+    ///
+    /// [aborting]: JoinHandle::abort
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub fn delete_after(
+        self,
+        cache_http: impl CacheHttp + 'static,
+        delay: Duration,
+    ) -> JoinHandle<Result<()>> {
+        spawn_named("message::delete_after", async move {
+            sleep(delay).await;
+
+            self.delete(cache_http).await
+        })
+    }
+
+    #[cfg(feature = "cache")]
+    fn check_delete_permissions(&self, cache: &Cache) -> Result<()> {
+        if self.author.id != cache.current_user_id() {
+            if self.is_private() {
+                return Err(Error::Model(ModelError::NotAuthor));
             }
+            utils::user_has_perms_cache(
+                cache,
+                self.channel_id,
+                self.guild_id,
+                Permissions::MANAGE_MESSAGES,
+            )?;
         }
 
-        self.channel_id.delete_message(&cache_http.http(), self.id).await
+        Ok(())
+    }
+
+    async fn send_delete(&self, http: &Http) -> Result<()> {
+        match self.channel_id.delete_message(http, self.id).await {
+            Err(Error::Http(why))
+                if why.discord_error_code() == Some(json_error_codes::UNKNOWN_MESSAGE) =>
+            {
+                Err(Error::Model(ModelError::MessageDeleted))
+            },
+            result => result,
+        }
     }
 
     /// Deletes all of the [`Reaction`]s associated with the message.
@@ -311,6 +688,11 @@ impl Message {
     ///
     /// **Note**: Requires that the current user be the author of the message.
     ///
+    /// **Note**: Discord does not allow adding, replacing or removing stickers
+    /// when editing a message. The [`Self::sticker_items`] of the message are
+    /// left untouched by an edit, but if `sticker_ids` are provided anyway they
+    /// are validated the same way as when sending a message.
+    ///
     /// # Examples
     ///
     /// Edit a message with new content:
@@ -330,6 +712,10 @@ impl Message {
     /// is over [`the limit`], containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::EmbedAmount`] or [`ModelError::EmbedTooLarge`]
+    /// if the embeds are over Discord's limits, and a
+    /// [`ModelError::StickerAmount`] if more than 3 sticker ids were provided.
+    ///
     /// [`the limit`]: crate::builder::EditMessage::content
     pub async fn edit<'a, F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
     where
@@ -366,6 +752,7 @@ impl Message {
 
     async fn _send_edit<'a>(&mut self, http: &Http, builder: EditMessage<'a>) -> Result<()> {
         let map = json::hashmap_to_json_map(builder.0);
+        Message::check_lengths(&map)?;
 
         *self = http
             .edit_message_and_attachments(
@@ -378,107 +765,691 @@ impl Message {
         Ok(())
     }
 
-    pub(crate) fn transform_content(&mut self) {
-        match self.kind {
-            MessageType::PinsAdd => {
-                self.content =
-                    format!("{} pinned a message to this channel. See all the pins.", self.author);
-            },
-            MessageType::MemberJoin => {
-                let sec = self.timestamp.unix_timestamp() as usize;
-                let chosen = constants::JOIN_MESSAGES[sec % constants::JOIN_MESSAGES.len()];
-
-                self.content = if chosen.contains("$user") {
-                    chosen.replace("$user", &self.author.mention().to_string())
-                } else {
-                    chosen.to_string()
-                };
-            },
-            _ => {},
-        }
-    }
-
-    /// Returns message content, but with user and role mentions replaced with
-    /// names and everyone/here mentions cancelled.
-    #[cfg(feature = "cache")]
-    pub fn content_safe(&self, cache: impl AsRef<Cache>) -> String {
-        let mut result = self.content.clone();
-
-        // First replace all user mentions.
-        for u in &self.mentions {
-            let mut at_distinct = String::with_capacity(38);
-            at_distinct.push('@');
-            at_distinct.push_str(&u.name);
-            at_distinct.push('#');
-            write!(at_distinct, "{:04}", u.discriminator).unwrap();
-
-            let mut m = u.mention().to_string();
-            // Check whether we're replacing a nickname mention or a normal mention.
-            // `UserId::mention` returns a normal mention. If it isn't present in the message, it's a nickname mention.
-            if !result.contains(&m) {
-                m.insert(2, '!');
-            }
-
-            result = result.replace(&m, &at_distinct);
+    /// Edits the content of this message, but only if it differs from the
+    /// current [`Self::content`].
+    ///
+    /// This avoids redundant requests, and their rate limit cost, when
+    /// repeatedly re-rendering a message such as a live status message.
+    ///
+    /// Returns `Ok(false)` if the content was identical and no request was
+    /// made, or `Ok(true)` if the message was edited.
+    ///
+    /// **Note**: Requires that the current user be the author of the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::edit`].
+    pub async fn edit_if_changed(
+        &mut self,
+        cache_http: impl CacheHttp,
+        new_content: &str,
+    ) -> Result<bool> {
+        if self.content == new_content {
+            return Ok(false);
         }
 
-        // Then replace all role mentions.
-        for id in &self.mention_roles {
-            let mention = id.mention().to_string();
-
-            if let Some(role) = id.to_role_cached(&cache) {
-                result = result.replace(&mention, &format!("@{}", role.name));
-            } else {
-                result = result.replace(&mention, "@deleted-role");
-            }
-        }
+        self.edit(cache_http, |m| m.content(new_content)).await?;
 
-        // And finally replace everyone and here mentions.
-        result.replace("@everyone", "@\u{200B}everyone").replace("@here", "@\u{200B}here")
+        Ok(true)
     }
 
-    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
-    /// certain [`Emoji`].
-    ///
-    /// The default `limit` is `50` - specify otherwise to receive a different
-    /// maximum number of users. The maximum that may be retrieve at a time is
-    /// `100`, if a greater number is provided then it is automatically reduced.
-    ///
-    /// The optional `after` attribute is to retrieve the users after a certain
-    /// user. This is useful for pagination.
+    /// Replaces the components of the message, leaving its content and embeds
+    /// intact.
     ///
-    /// **Note**: Requires the [Read Message History] permission.
+    /// The components are rebuilt from scratch by `f`. This is useful to
+    /// update the label of a button or to disable a select menu after an
+    /// interaction.
     ///
-    /// **Note**: If the passed reaction_type is a custom guild emoji, it must contain the name. So,
-    /// [`Emoji`] or [`EmojiIdentifier`] will always work, [`ReactionType`] only if
-    /// [`ReactionType::Custom::name`] is Some, and **[`EmojiId`] will never work**.
+    /// **Note**: Only the author of a message can modify it.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission.
-    ///
-    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
-    #[inline]
-    pub async fn reaction_users(
-        &self,
-        http: impl AsRef<Http>,
-        reaction_type: impl Into<ReactionType>,
-        limit: Option<u8>,
-        after: impl Into<Option<UserId>>,
-    ) -> Result<Vec<User>> {
-        self.channel_id.reaction_users(&http, self.id, reaction_type, limit, after).await
+    /// Returns the same errors as [`Self::edit`].
+    pub async fn edit_components<F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
+    {
+        self.edit(cache_http, |m| m.components(f)).await
     }
 
-    /// Returns the associated [`Guild`] for the message if one is in the cache.
+    /// Replaces the embeds of the message, leaving its content, attachments
+    /// and components intact.
     ///
-    /// Returns [`None`] if the guild's Id could not be found via [`Self::guild_id`] or
-    /// if the Guild itself is not cached.
+    /// Unlike [`Self::edit`], only the embeds are sent to Discord.
     ///
-    /// Requires the `cache` feature be enabled.
-    #[cfg(feature = "cache")]
-    pub fn guild(&self, cache: impl AsRef<Cache>) -> Option<Guild> {
-        cache.as_ref().guild(self.guild_id?)
-    }
+    /// **Note**: Only the author of a message can modify it.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidUser`] if the
+    /// current user is not the author.
+    ///
+    /// Returns a [`ModelError::EmbedAmount`] if more than 10 embeds are given,
+    /// or a [`ModelError::EmbedTooLarge`] if an embed is too large.
+    pub async fn edit_embeds(
+        &mut self,
+        cache_http: impl CacheHttp,
+        embeds: Vec<CreateEmbed>,
+    ) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if self.author.id != cache.current_user_id() {
+                    return Err(Error::Model(ModelError::InvalidUser));
+                }
+            }
+        }
+
+        if embeds.len() > constants::EMBED_MAX_COUNT {
+            return Err(Error::Model(ModelError::EmbedAmount));
+        }
+
+        let mut builder = EditMessage::default();
+        builder.set_embeds(embeds);
+        let map = json::hashmap_to_json_map(builder.0);
+        Message::check_lengths(&map)?;
+
+        *self =
+            cache_http.http().edit_message(self.channel_id.0, self.id.0, &Value::from(map)).await?;
+
+        Ok(())
+    }
+
+    /// Edits this message through the webhook that sent it, using the given
+    /// webhook `token`.
+    ///
+    /// Messages sent by a webhook can not be edited through [`Self::edit`];
+    /// instead the webhook's Id (taken from [`Self::webhook_id`]) and token
+    /// must be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the message was not sent by a
+    /// webhook.
+    ///
+    /// May also return an [`Error::Http`] if the content is malformed, the
+    /// token is invalid, or the message does not belong to the webhook.
+    pub async fn edit_webhook_message<F>(
+        &mut self,
+        http: impl AsRef<Http>,
+        token: &str,
+        f: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&mut EditWebhookMessage) -> &mut EditWebhookMessage,
+    {
+        let webhook_id = self.webhook_id.ok_or(Error::Model(ModelError::ItemMissing))?;
+
+        let mut edit_webhook_message = EditWebhookMessage::default();
+        f(&mut edit_webhook_message);
+
+        let map = json::hashmap_to_json_map(edit_webhook_message.0);
+
+        *self = http.as_ref().edit_webhook_message(webhook_id.0, token, self.id.0, &map).await?;
+        Ok(())
+    }
+
+    /// Deletes this message through the webhook that sent it, using the given
+    /// webhook `token`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the message was not sent by a
+    /// webhook.
+    ///
+    /// May also return an [`Error::Http`] if the token is invalid or the
+    /// message does not belong to the webhook.
+    pub async fn delete_webhook_message(&self, http: impl AsRef<Http>, token: &str) -> Result<()> {
+        let webhook_id = self.webhook_id.ok_or(Error::Model(ModelError::ItemMissing))?;
+
+        http.as_ref().delete_webhook_message(webhook_id.0, token, self.id.0).await
+    }
+
+    pub(crate) fn transform_content(&mut self) {
+        match self.kind {
+            MessageType::PinsAdd => {
+                self.content =
+                    format!("{} pinned a message to this channel. See all the pins.", self.author);
+            },
+            MessageType::MemberJoin => {
+                let sec = self.timestamp.unix_timestamp() as usize;
+                let chosen = constants::JOIN_MESSAGES[sec % constants::JOIN_MESSAGES.len()];
+
+                self.content = if chosen.contains("$user") {
+                    chosen.replace("$user", &self.author.mention().to_string())
+                } else {
+                    chosen.to_string()
+                };
+            },
+            _ => {},
+        }
+    }
+
+    /// Returns message content, but with mentions replaced with names and
+    /// everyone/here mentions cancelled.
+    ///
+    /// The [`ContentSafeOptions`] decide which kinds of mentions are cleaned
+    /// and how they are displayed. Its [`Default`] cleans all of them, while
+    /// custom emojis are left untouched unless [`ContentSafeOptions::clean_emoji`]
+    /// is set.
+    ///
+    /// Channel mentions are resolved using [`Self::mention_channels`] first,
+    /// which also covers channels of other guilds, and then the cache.
+    ///
+    /// See [`utils::content_safe`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Show user mentions by their nickname in the guild of the message,
+    /// while leaving role mentions untouched:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # use serenity::model::channel::Message;
+    /// use serenity::utils::ContentSafeOptions;
+    ///
+    /// # fn example(cache: &Cache, message: &Message) {
+    /// let mut options = ContentSafeOptions::default().clean_role(false);
+    /// if let Some(guild_id) = message.guild_id {
+    ///     options = options.display_as_member_from(guild_id).show_discriminator(false);
+    /// }
+    ///
+    /// let content = message.content_safe(cache, &options);
+    /// # }
+    /// ```
+    ///
+    /// [`utils::content_safe`]: crate::utils::content_safe
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn content_safe(&self, cache: impl AsRef<Cache>, options: &ContentSafeOptions) -> String {
+        crate::utils::content_safe_with_channels(
+            cache,
+            &self.content,
+            options,
+            &self.mentions,
+            &self.mention_channels,
+        )
+    }
+
+    /// Returns message content with only `@everyone` and `@here` mentions
+    /// cancelled, by inserting a zero-width space after the `@`.
+    ///
+    /// User and role mentions are left untouched. Unlike [`Self::content_safe`],
+    /// this does not require the `cache` feature, which makes it suitable for
+    /// re-posting content to a logging channel without triggering mass pings.
+    #[must_use]
+    pub fn content_no_mass_ping(&self) -> String {
+        self.content.replace("@everyone", "@\u{200B}everyone").replace("@here", "@\u{200B}here")
+    }
+
+    /// Returns whether the content contains the given word, matched on word
+    /// boundaries.
+    ///
+    /// Matching is case-insensitive and Unicode-aware, so `"ass"` does not
+    /// match `"class"`. Zero-width characters are stripped from the content
+    /// beforehand, so they can not be used to evade the match.
+    ///
+    /// The word may also be a phrase of multiple words.
+    #[must_use]
+    pub fn contains_word(&self, word: &str) -> bool {
+        contains_word(&normalize_words(&self.content), &word.to_lowercase())
+    }
+
+    /// Returns whether the content contains any of the given words, matched
+    /// on word boundaries.
+    ///
+    /// See [`Self::contains_word`] for how words are matched.
+    #[must_use]
+    pub fn contains_any_word(&self, words: &[&str]) -> bool {
+        let content = normalize_words(&self.content);
+
+        words.iter().any(|word| contains_word(&content, &word.to_lowercase()))
+    }
+
+    /// Returns whether this message has the same content as another one,
+    /// ignoring trivial variations.
+    ///
+    /// The contents are compared case-insensitively, with surrounding
+    /// whitespace and zero-width characters removed. This is useful to detect
+    /// repeated spam messages.
+    ///
+    /// Messages without content never match.
+    #[must_use]
+    pub fn content_matches(&self, other: &Message) -> bool {
+        let content = normalize_words(&self.content);
+        let content = content.trim();
+
+        !content.is_empty() && content == normalize_words(&other.content).trim()
+    }
+
+    /// Returns the codes of the Discord invites linked in the content, in
+    /// order of appearance.
+    ///
+    /// The `discord.gg/{code}`, `discord.com/invite/{code}` and
+    /// `discordapp.com/invite/{code}` forms are recognised, with or without a
+    /// scheme. Links inside code blocks and inline code are ignored, as
+    /// Discord does not render them as links.
+    #[must_use]
+    pub fn invite_codes(&self) -> Vec<String> {
+        const HOSTS: [&str; 3] = ["discord.gg/", "discord.com/invite/", "discordapp.com/invite/"];
+
+        let text = strip_code(&self.content);
+        let lowercase = text.to_ascii_lowercase();
+
+        let mut codes: Vec<(usize, String)> = HOSTS
+            .iter()
+            .flat_map(|host| lowercase.match_indices(host))
+            .filter(|(start, _)| {
+                // Reject hosts like `notdiscord.gg`, but allow `www.discord.gg`.
+                let before = &lowercase[..*start];
+                before.ends_with("www.")
+                    || !matches!(
+                        before.chars().next_back(),
+                        Some(c) if c.is_alphanumeric() || c == '.' || c == '-'
+                    )
+            })
+            .filter_map(|(start, host)| {
+                let rest = &text[start + host.len()..];
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+                    .unwrap_or(rest.len());
+
+                (end > 0).then(|| (start, rest[..end].to_string()))
+            })
+            .collect();
+
+        codes.sort_by_key(|(start, _)| *start);
+        codes.into_iter().map(|(_, code)| code).collect()
+    }
+
+    /// Returns whether the content links to a Discord invite.
+    ///
+    /// See [`Self::invite_codes`] for the recognised forms.
+    #[must_use]
+    pub fn contains_invite(&self) -> bool {
+        !self.invite_codes().is_empty()
+    }
+
+    /// Strips a command prefix or a leading mention of the bot from the
+    /// content.
+    ///
+    /// The `prefixes` are tried in order, then a leading `<@bot_id>` or
+    /// `<@!bot_id>` mention if `bot_id` is given. Whitespace following the
+    /// mention is removed as well, so both `!ping` and `@bot ping` result in
+    /// `ping` with a prefix of `!`.
+    ///
+    /// Returns [`None`] if the content starts with neither.
+    #[must_use]
+    pub fn strip_prefixes(&self, prefixes: &[&str], bot_id: Option<UserId>) -> Option<&str> {
+        if let Some(rest) = prefixes.iter().find_map(|prefix| self.content.strip_prefix(prefix)) {
+            return Some(rest);
+        }
+
+        let bot_id = bot_id?;
+        let rest = self
+            .content
+            .strip_prefix(&format!("<@{}>", bot_id.0))
+            .or_else(|| self.content.strip_prefix(&format!("<@!{}>", bot_id.0)))?;
+
+        Some(rest.trim_start())
+    }
+
+    /// Splits the content into [`ContentToken`]s, in the order they appear.
+    ///
+    /// User (including the `<@!ID>` nickname form), role and channel mentions,
+    /// custom emojis and timestamps are recognised; everything in between is
+    /// returned as [`ContentToken::Text`]. Malformed markup, such as a mention
+    /// with a non-numeric ID, is kept as text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// use serenity::model::channel::ContentToken;
+    ///
+    /// # fn example(message: &Message) {
+    /// for token in message.parse_content() {
+    ///     match token {
+    ///         ContentToken::UserMention(user_id) => println!("mentions {}", user_id),
+    ///         ContentToken::Text(text) => println!("text: {}", text),
+    ///         _ => {},
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_content(&self) -> Vec<ContentToken> {
+        tokenize_content(&self.content).into_iter().map(|(_, token)| token).collect()
+    }
+
+    /// Like [`Self::parse_content`], but also returns the offset at which each
+    /// token starts, counted in unicode code points.
+    #[must_use]
+    pub fn parse_content_with_offsets(&self) -> Vec<(usize, ContentToken)> {
+        tokenize_content(&self.content)
+    }
+
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
+    /// certain [`Emoji`].
+    ///
+    /// The default `limit` is `50` - specify otherwise to receive a different
+    /// maximum number of users. The maximum that may be retrieve at a time is
+    /// `100`, if a greater number is provided then it is automatically reduced.
+    ///
+    /// The optional `after` attribute is to retrieve the users after a certain
+    /// user. This is useful for pagination.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// **Note**: If the passed reaction_type is a custom guild emoji, it must contain the name. So,
+    /// [`Emoji`] or [`EmojiIdentifier`] will always work, [`ReactionType`] only if
+    /// [`ReactionType::Custom::name`] is Some, and **[`EmojiId`] will never work**.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    #[inline]
+    pub async fn reaction_users(
+        &self,
+        http: impl AsRef<Http>,
+        reaction_type: impl Into<ReactionType>,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.channel_id.reaction_users(&http, self.id, reaction_type, limit, after).await
+    }
+
+    /// Streams over every [`User`] who reacted to the message with the given
+    /// [`ReactionType`].
+    ///
+    /// This is equivalent to repeated calls to [`Self::reaction_users`], using
+    /// the Id of the last user of a page as the `after` of the next one. Users
+    /// are fetched in pages of 100, and the stream ends once Discord returns a
+    /// page with fewer users than that.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # async fn run(http: Http, message: Message) {
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut users = message.reaction_users_iter(&http, '\u{1f44d}').boxed();
+    /// while let Some(user_result) = users.next().await {
+    ///     match user_result {
+    ///         Ok(user) => println!("{} reacted", user.name),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Yields an [`Error::Http`] if the current user lacks permission, after
+    /// which the stream ends.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub fn reaction_users_iter<H: AsRef<Http>>(
+        &self,
+        http: H,
+        reaction_type: impl Into<ReactionType>,
+    ) -> impl Stream<Item = Result<User>> {
+        let channel_id = self.channel_id;
+        let message_id = self.id;
+        let init_state = ReactionUsersState {
+            http,
+            reaction_type: reaction_type.into(),
+            buffer: Vec::new(),
+            after: None,
+            exhausted: false,
+        };
+
+        futures::stream::unfold(init_state, move |mut state| async move {
+            if state.buffer.is_empty() && !state.exhausted {
+                let page = state
+                    .http
+                    .as_ref()
+                    .get_reaction_users(
+                        channel_id.0,
+                        message_id.0,
+                        &state.reaction_type,
+                        REACTION_USERS_PAGE_SIZE,
+                        state.after,
+                    )
+                    .await;
+
+                match page {
+                    Ok(mut page) => {
+                        state.after = page.last().map(|user| user.id.0);
+                        state.exhausted =
+                            page.len() < REACTION_USERS_PAGE_SIZE as usize || state.after.is_none();
+
+                        // Popping from the back yields the users in the order
+                        // Discord returned them.
+                        page.reverse();
+                        state.buffer = page;
+                    },
+                    Err(error) => {
+                        state.exhausted = true;
+
+                        return Some((Err(error), state));
+                    },
+                }
+            }
+
+            state.buffer.pop().map(|user| (Ok(user), state))
+        })
+    }
+
+    /// Gets every [`User`] who reacted to the message, for each of the
+    /// reactions in [`Self::reactions`].
+    ///
+    /// The users of a single reaction are fetched page by page, while up to
+    /// `concurrency` reactions are fetched at the same time. If [`None`] is
+    /// passed, a conservative default of `3` is used.
+    ///
+    /// **Note**: A higher concurrency makes fetching messages with many
+    /// distinct reactions faster, but every page is a separate request, so it
+    /// also makes hitting rate limits more likely.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if any
+    /// of the requests fails.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn all_reaction_users(
+        &self,
+        http: impl AsRef<Http>,
+        concurrency: Option<usize>,
+    ) -> Result<HashMap<ReactionType, Vec<User>>> {
+        let http = http.as_ref();
+        let concurrency = concurrency.unwrap_or(DEFAULT_REACTION_USERS_CONCURRENCY).max(1);
+
+        futures::stream::iter(self.reactions.iter().map(|reaction| async move {
+            let users = self.reaction_users_paginated(http, &reaction.reaction_type).await?;
+
+            Ok((reaction.reaction_type.clone(), users))
+        }))
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await
+    }
+
+    async fn reaction_users_paginated(
+        &self,
+        http: &Http,
+        reaction_type: &ReactionType,
+    ) -> Result<Vec<User>> {
+        self.reaction_users_iter(http, reaction_type.clone()).try_collect().await
+    }
+
+    /// Applies a [`Reaction`] removal received over the gateway to the local
+    /// [`Self::reactions`] state.
+    ///
+    /// The count of the matching reaction is decremented, and it is dropped
+    /// entirely once nobody holds it anymore. If the removal was made by the
+    /// current user, whose Id is passed as `current_user_id`, [`MessageReaction::me`]
+    /// is reset as well.
+    ///
+    /// Returns `false` if the reaction does not belong to this message, or if
+    /// no matching reaction was present.
+    pub fn apply_reaction_remove(&mut self, reaction: &Reaction, current_user_id: UserId) -> bool {
+        if reaction.message_id != self.id {
+            return false;
+        }
+
+        let position = self
+            .reactions
+            .iter()
+            .position(|r| reaction_type_matches(&r.reaction_type, &reaction.emoji));
+
+        if let Some(index) = position {
+            let message_reaction = &mut self.reactions[index];
+            message_reaction.count = message_reaction.count.saturating_sub(1);
+
+            if reaction.user_id == Some(current_user_id) {
+                message_reaction.me = false;
+            }
+
+            if message_reaction.count == 0 {
+                self.reactions.remove(index);
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns whether the current user holds the given reaction on this
+    /// message, according to the local [`Self::reactions`] state.
+    ///
+    /// This is useful for reaction-role menus, to reconcile the menu's
+    /// canonical reactions after gateway events have been applied with
+    /// [`Self::apply_reaction_remove`].
+    ///
+    /// Returns [`None`] if nobody reacted with the given emoji.
+    pub fn reaction_role_state(&self, emoji: impl Into<ReactionType>) -> Option<bool> {
+        let emoji = emoji.into();
+
+        self.reactions
+            .iter()
+            .find(|r| reaction_type_matches(&r.reaction_type, &emoji))
+            .map(|r| r.me)
+    }
+
+    /// Returns how many users reacted to the message with the given emoji,
+    /// according to the local [`Self::reactions`] state.
+    ///
+    /// Custom emojis are matched by their Id only, so the name does not need
+    /// to be known. Returns `0` if nobody reacted with the emoji.
+    #[must_use]
+    pub fn reaction_count(&self, reaction_type: impl Into<ReactionType>) -> u64 {
+        let reaction_type = reaction_type.into();
+
+        self.reactions
+            .iter()
+            .find(|r| reaction_type_matches(&r.reaction_type, &reaction_type))
+            .map_or(0, |r| r.count)
+    }
+
+    /// Returns whether the current user reacted to the message with the given
+    /// emoji, according to the local [`Self::reactions`] state.
+    ///
+    /// See [`Self::reaction_role_state`] to also tell whether anyone reacted
+    /// with the emoji.
+    #[inline]
+    #[must_use]
+    pub fn has_reaction_from_me(&self, reaction_type: impl Into<ReactionType>) -> bool {
+        self.reaction_role_state(reaction_type).unwrap_or(false)
+    }
+
+    /// Makes the current user's reactions on the message exactly the given
+    /// set.
+    ///
+    /// Reactions in `desired` that the current user does not hold yet are
+    /// added in order, and reactions the current user holds according to
+    /// [`Self::reactions`] that are not in `desired` are removed.
+    ///
+    /// **Note**: This relies on [`Self::reactions`] being up to date, see
+    /// [`Self::apply_reaction_remove`]. The local state is not updated by this
+    /// method.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the required permissions.
+    ///
+    /// Returns [`Error::Http`] if any of the requests fails, in which case the
+    /// remaining reactions are not processed.
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    pub async fn set_reactions(
+        &self,
+        cache_http: impl CacheHttp,
+        desired: &[ReactionType],
+    ) -> Result<()> {
+        let held: Vec<&ReactionType> =
+            self.reactions.iter().filter(|r| r.me).map(|r| &r.reaction_type).collect();
+
+        for reaction_type in &held {
+            if !desired.iter().any(|d| reaction_type_matches(d, reaction_type)) {
+                self.unreact(&cache_http, (*reaction_type).clone()).await?;
+            }
+        }
+
+        for reaction_type in desired {
+            if !held.iter().any(|h| reaction_type_matches(h, reaction_type)) {
+                self.react(&cache_http, reaction_type.clone()).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Re-fetches the message to replace the [`Self::attachments`] with ones
+    /// carrying freshly signed URLs.
+    ///
+    /// Attachment URLs expire after some time, which can be checked with
+    /// [`Attachment::is_url_expired`].
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if
+    /// the message was deleted.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn refresh_attachment_urls(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        let message = http.as_ref().get_message(self.channel_id.0, self.id.0).await?;
+        self.attachments = message.attachments;
+
+        Ok(())
+    }
+
+    /// Returns the associated [`Guild`] for the message if one is in the cache.
+    ///
+    /// Returns [`None`] if the guild's Id could not be found via [`Self::guild_id`] or
+    /// if the Guild itself is not cached.
+    ///
+    /// Requires the `cache` feature be enabled.
+    #[cfg(feature = "cache")]
+    pub fn guild(&self, cache: impl AsRef<Cache>) -> Option<Guild> {
+        cache.as_ref().guild(self.guild_id?)
+    }
 
     /// Returns a field to the [`Guild`] for the message if one is in the cache.
     /// The field can be selected via the `field_accessor`.
@@ -507,6 +1478,197 @@ impl Message {
         self.guild_id.is_none()
     }
 
+    /// Classifies the channel the message was sent in, based on the kind of
+    /// the cached channel.
+    ///
+    /// Returns [`MessageOrigin::Unknown`] if the channel is not in the cache.
+    ///
+    /// Requires the `cache` feature be enabled.
+    #[cfg(feature = "cache")]
+    pub fn origin(&self, cache: impl AsRef<Cache>) -> MessageOrigin {
+        let cache = cache.as_ref();
+
+        let kind = match cache.channel(self.channel_id) {
+            Some(Channel::Guild(channel)) => Some(channel.kind),
+            Some(Channel::Private(_)) => return MessageOrigin::Dm,
+            Some(_) => None,
+            // Threads are cached as part of their guild, not with the other channels.
+            None => self.guild_id.and_then(|guild_id| {
+                cache.guild_field(guild_id, |guild| {
+                    guild.threads.iter().find(|t| t.id == self.channel_id).map(|t| t.kind)
+                })?
+            }),
+        };
+
+        kind.map_or(MessageOrigin::Unknown, MessageOrigin::from_channel_type)
+    }
+
+    /// True if the message was sent by a bot user.
+    ///
+    /// Messages sent by webhooks are not considered to be sent by a bot, see
+    /// [`Self::is_webhook`].
+    #[inline]
+    #[must_use]
+    pub fn is_from_bot(&self) -> bool {
+        self.author.bot && self.webhook_id.is_none()
+    }
+
+    /// True if the message was sent by a webhook.
+    #[inline]
+    #[must_use]
+    pub fn is_webhook(&self) -> bool {
+        self.webhook_id.is_some()
+    }
+
+    /// Returns the name the message's author is displayed with, without
+    /// hitting the cache.
+    ///
+    /// For webhook messages this is the webhook's display name, which Discord
+    /// sends as the author's name. Otherwise the author's nickname in the
+    /// guild is preferred, if the message carries one, falling back to their
+    /// username.
+    #[must_use]
+    pub fn effective_author_name(&self) -> &str {
+        if self.is_webhook() {
+            return &self.author.name;
+        }
+
+        self.member
+            .as_ref()
+            .and_then(|member| member.nick.as_deref())
+            .unwrap_or(&self.author.name)
+    }
+
+    /// True if the message is an inline reply to another message.
+    ///
+    /// The replied-to message may since have been deleted, see
+    /// [`Self::replied_author`].
+    #[must_use]
+    pub fn is_reply(&self) -> bool {
+        self.kind == MessageType::InlineReply && self.message_reference.is_some()
+    }
+
+    /// Returns the author of the message this message replies to.
+    ///
+    /// Returns [`None`] if this is not a reply, or Discord did not include the
+    /// [`Self::referenced_message`], e.g. because it was deleted.
+    #[must_use]
+    pub fn replied_author(&self) -> Option<&User> {
+        self.referenced_message.as_ref().map(|message| &message.author)
+    }
+
+    /// True if the message has all of the given flags set.
+    ///
+    /// Returns `false` if Discord did not send the [`Self::flags`].
+    #[must_use]
+    pub fn flags_contains(&self, flags: MessageFlags) -> bool {
+        matches!(self.flags, Some(own) if own.contains(flags))
+    }
+
+    /// True if the message is an ephemeral interaction response, which is
+    /// only visible to the user who invoked the interaction.
+    #[inline]
+    #[must_use]
+    pub fn is_ephemeral(&self) -> bool {
+        self.flags_contains(MessageFlags::EPHEMERAL)
+    }
+
+    /// True if the message is a deferred interaction response that is still
+    /// shown as "thinking".
+    #[inline]
+    #[must_use]
+    pub fn is_loading(&self) -> bool {
+        self.flags_contains(MessageFlags::LOADING)
+    }
+
+    /// True if the message has been published to the channels following its
+    /// news channel.
+    #[inline]
+    #[must_use]
+    pub fn is_crossposted(&self) -> bool {
+        self.flags_contains(MessageFlags::CROSSPOSTED)
+    }
+
+    /// True if the message is a system message, such as a member join or a
+    /// pin notification, rather than one authored by a user or application.
+    ///
+    /// See [`MessageType::is_system`].
+    #[inline]
+    #[must_use]
+    pub fn is_system(&self) -> bool {
+        self.kind.is_system()
+    }
+
+    /// True if the message is one a bot would usually want to process: a
+    /// regular message or reply with non-empty content, sent by a user that is
+    /// neither a bot nor a webhook.
+    ///
+    /// The individual checks are available as [`Self::is_from_bot`],
+    /// [`Self::is_webhook`] and [`Self::is_system`] to compose other variants.
+    #[must_use]
+    pub fn is_processable(&self) -> bool {
+        !self.author.bot
+            && !self.is_webhook()
+            && matches!(self.kind, MessageType::Regular | MessageType::InlineReply)
+            && !self.content.is_empty()
+    }
+
+    /// Orders messages by their creation time, as derived from their IDs.
+    ///
+    /// Unlike [`Self::timestamp`], this does not depend on where the message
+    /// was received from, so it can be used to reliably sort messages merged
+    /// from different sources:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Message;
+    /// # fn example(mut messages: Vec<Message>) {
+    /// messages.sort_by(Message::cmp_by_creation);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cmp_by_creation(&self, other: &Message) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+
+    /// Returns how long ago the message was sent, according to
+    /// [`Self::timestamp`] and the system clock.
+    ///
+    /// The age is accurate to the second. If the timestamp lies in the future,
+    /// for example due to clock skew, a zero duration is returned.
+    #[must_use]
+    pub fn age(&self) -> Duration {
+        let sent_at = u64::try_from(self.timestamp.unix_timestamp()).unwrap_or(0);
+        let sent_at = UNIX_EPOCH + Duration::from_secs(sent_at);
+
+        SystemTime::now().duration_since(sent_at).unwrap_or_default()
+    }
+
+    /// True if the message was sent more than `age` ago.
+    ///
+    /// See [`Self::age`] for how the age of the message is determined.
+    #[inline]
+    #[must_use]
+    pub fn is_older_than(&self, age: Duration) -> bool {
+        self.age() > age
+    }
+
+    /// Returns when the message was last changed: the
+    /// [`Self::edited_timestamp`] if it was edited, otherwise the
+    /// [`Self::timestamp`] at which it was sent.
+    #[inline]
+    #[must_use]
+    pub fn last_activity(&self) -> Timestamp {
+        self.edited_timestamp.unwrap_or(self.timestamp)
+    }
+
+    /// True if the message has been edited since it was sent.
+    #[inline]
+    #[must_use]
+    pub fn was_edited(&self) -> bool {
+        self.edited_timestamp.is_some()
+    }
+
     /// Retrieves a clone of the author's Member instance, if this message was
     /// sent in a guild.
     ///
@@ -553,6 +1715,168 @@ impl Message {
         }
     }
 
+    /// Checks the textual length of an embed, as returned by
+    /// [`CreateEmbed::len`], against Discord's maximum of 6000 unicode code
+    /// points.
+    ///
+    /// Returns [`None`] if the embed is within the limit, otherwise returns
+    /// [`Some`] with an inner value of how many unicode code points the embed
+    /// is over.
+    #[must_use]
+    pub fn embed_overflow_length(embed: &CreateEmbed) -> Option<usize> {
+        let length = embed.len();
+
+        if length > constants::EMBED_MAX_LENGTH {
+            Some(length - constants::EMBED_MAX_LENGTH)
+        } else {
+            None
+        }
+    }
+
+    /// Checks a whole message against Discord's length limits: the content
+    /// against the maximum message length, see [`Self::overflow_length`], and
+    /// the embeds against the maximum of 6000 unicode code points, which is
+    /// shared by all embeds of a message.
+    ///
+    /// Returns [`None`] if the message is within the limits, otherwise returns
+    /// [`Some`] with an inner value of how many unicode code points the
+    /// content and the embeds are over in total.
+    #[must_use]
+    pub fn payload_overflow_length(content: &str, embeds: &[CreateEmbed]) -> Option<usize> {
+        let embeds_length: usize = embeds.iter().map(CreateEmbed::len).sum();
+        let embeds_over = embeds_length.saturating_sub(constants::EMBED_MAX_LENGTH);
+        let over = Message::overflow_length(content).unwrap_or(0) + embeds_over;
+
+        if over > 0 {
+            Some(over)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length of the message's content in unicode code points.
+    ///
+    /// This is the same basis Discord uses for its message length limit, see
+    /// [`Self::overflow_length`].
+    #[inline]
+    #[must_use]
+    pub fn content_length(&self) -> usize {
+        self.content.chars().count()
+    }
+
+    /// Returns the part of the content starting at the `start`th code point
+    /// and spanning up to `len` code points.
+    ///
+    /// Unlike slicing [`Self::content`] by byte offsets, this never panics on
+    /// multibyte characters: both bounds are clamped to the content, so an
+    /// out-of-range `start` returns an empty string.
+    #[must_use]
+    pub fn content_snippet(&self, start: usize, len: usize) -> &str {
+        let byte_offset = |chars: usize, from: &str| {
+            from.char_indices().nth(chars).map_or(from.len(), |(index, _)| index)
+        };
+
+        let rest = &self.content[byte_offset(start, &self.content)..];
+        &rest[..byte_offset(len, rest)]
+    }
+
+    /// Returns the [`Self::nonce`] as a string, regardless of whether Discord
+    /// sent it as a string or as an integer.
+    ///
+    /// This is convenient for matching a sent message with its echo.
+    #[must_use]
+    pub fn nonce_str(&self) -> Option<String> {
+        self.nonce.as_ref().map(ToString::to_string)
+    }
+
+    /// Returns the number of user-perceived characters (extended grapheme
+    /// clusters) in the message's content.
+    ///
+    /// This may be lower than [`Self::content_length`], for example when the
+    /// content contains emojis made of multiple code points.
+    ///
+    /// Requires the `unicode_segmentation` feature be enabled.
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn content_graphemes(&self) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        self.content.graphemes(true).count()
+    }
+
+    /// Returns the source URLs of the images, thumbnails and videos of all
+    /// embeds of the message, see [`Embed::media_urls`].
+    #[must_use]
+    pub fn all_embed_media_urls(&self) -> Vec<&str> {
+        self.embeds.iter().flat_map(Embed::media_urls).collect()
+    }
+
+    /// Returns an iterator over the image attachments of the message.
+    ///
+    /// See [`Attachment::media_type`] for how the kind of an attachment is
+    /// determined.
+    pub fn image_attachments(&self) -> impl Iterator<Item = &Attachment> {
+        self.attachments_of_type("image")
+    }
+
+    /// Returns an iterator over the video attachments of the message.
+    ///
+    /// See [`Attachment::media_type`] for how the kind of an attachment is
+    /// determined.
+    pub fn video_attachments(&self) -> impl Iterator<Item = &Attachment> {
+        self.attachments_of_type("video")
+    }
+
+    /// Returns an iterator over the audio attachments of the message.
+    ///
+    /// See [`Attachment::media_type`] for how the kind of an attachment is
+    /// determined.
+    pub fn audio_attachments(&self) -> impl Iterator<Item = &Attachment> {
+        self.attachments_of_type("audio")
+    }
+
+    fn attachments_of_type<'a>(
+        &'a self,
+        media_type: &'a str,
+    ) -> impl Iterator<Item = &'a Attachment> {
+        self.attachments.iter().filter(move |a| a.media_type() == Some(media_type))
+    }
+
+    /// Returns a non-empty textual representation of the message, for use in
+    /// logs or search indexes.
+    ///
+    /// This is the [`Self::content`] if it is not empty. Otherwise a summary
+    /// of the embeds and attachments is synthesized, such as
+    /// `[embed: Some title]`, `[3 embeds]` or `[attachment: image.png]`.
+    ///
+    /// Returns an empty string if the message has neither content, embeds
+    /// nor attachments.
+    #[must_use]
+    pub fn display_content(&self) -> String {
+        if !self.content.is_empty() {
+            return self.content.clone();
+        }
+
+        let mut parts = Vec::new();
+
+        match self.embeds.as_slice() {
+            [] => {},
+            [embed] => match &embed.title {
+                Some(title) => parts.push(format!("[embed: {}]", title)),
+                None => parts.push("[embed]".to_string()),
+            },
+            embeds => parts.push(format!("[{} embeds]", embeds.len())),
+        }
+
+        match self.attachments.as_slice() {
+            [] => {},
+            [attachment] => parts.push(format!("[attachment: {}]", attachment.filename)),
+            attachments => parts.push(format!("[{} attachments]", attachments.len())),
+        }
+
+        parts.join(" ")
+    }
+
     /// Pins this message to its channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission.
@@ -563,8 +1887,58 @@ impl Message {
     /// [`ModelError::InvalidPermissions`] if the current user does not have
     /// the required permissions.
     ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum of 50 pinned messages. The cache does not keep track of pinned
+    /// messages, so this can only be detected once Discord rejects the request.
+    ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
     pub async fn pin(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self.set_pinned(cache_http, true, None).await
+    }
+
+    /// Pins this message to its channel, attaching a `reason` to the entry
+    /// in the audit log.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::pin`].
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
+    pub async fn pin_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self.set_pinned(cache_http, true, Some(reason)).await
+    }
+
+    /// Pins this message like [`Self::pin`], unless [`Self::pinned`] says it
+    /// already is, in which case no request is made.
+    ///
+    /// Returns whether the message was pinned by this call.
+    ///
+    /// **Note**: [`Self::pinned`] is not updated, and is only as recent as
+    /// this copy of the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::pin`].
+    pub async fn pin_if_needed(&self, cache_http: impl CacheHttp) -> Result<bool> {
+        if self.pinned {
+            return Ok(false);
+        }
+
+        self.set_pinned(cache_http, true, None).await?;
+
+        Ok(true)
+    }
+
+    async fn set_pinned(
+        &self,
+        cache_http: impl CacheHttp,
+        pinned: bool,
+        reason: Option<&str>,
+    ) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -579,7 +1953,20 @@ impl Message {
             }
         }
 
-        self.channel_id.pin(cache_http.http(), self.id.0).await
+        let http = cache_http.http();
+
+        if !pinned {
+            return http.unpin_message(self.channel_id.0, self.id.0, reason).await;
+        }
+
+        match http.pin_message(self.channel_id.0, self.id.0, reason).await {
+            Err(Error::Http(why))
+                if why.discord_error_code() == Some(json_error_codes::MAXIMUM_PINS_REACHED) =>
+            {
+                Err(Error::Model(ModelError::TooManyPins))
+            },
+            result => result,
+        }
     }
 
     /// React to the message with a custom [`Emoji`] or unicode character.
@@ -603,6 +1990,47 @@ impl Message {
         self._react(cache_http, reaction_type.into()).await
     }
 
+    /// Reacts to the message with each of the given reactions, one after the
+    /// other so that they are displayed in the given order.
+    ///
+    /// Returns the added reactions, in order.
+    ///
+    /// Unlike [`ChannelId::react_all`], which adds the same reaction to many
+    /// messages concurrently and returns a result per message, this adds many
+    /// reactions to one message sequentially and stops at the first error.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first reaction that could not be added, returning a
+    /// [`ReactAllError`] with the reactions added up to that point and the
+    /// error, which is one [`Self::react`] may return. The error converts into
+    /// an [`Error`] for use with `?`.
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    pub async fn react_all<R: Into<ReactionType>>(
+        &self,
+        cache_http: impl CacheHttp,
+        reactions: impl IntoIterator<Item = R>,
+    ) -> StdResult<Vec<Reaction>, ReactAllError> {
+        let mut added = Vec::new();
+
+        for reaction_type in reactions {
+            match self.react(&cache_http, reaction_type).await {
+                Ok(reaction) => added.push(reaction),
+                Err(error) => {
+                    return Err(ReactAllError {
+                        added,
+                        error,
+                    })
+                },
+            }
+        }
+
+        Ok(added)
+    }
+
     async fn _react(
         &self,
         cache_http: impl CacheHttp,
@@ -639,6 +2067,78 @@ impl Message {
         })
     }
 
+    /// Reacts to the message with a custom emoji of the message's guild,
+    /// looked up by its name.
+    ///
+    /// The emoji is looked up in the cache if the guild is cached, or else
+    /// fetched over the REST API. If the guild is cached but has no emoji with
+    /// the name, no request is made.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmojiNotFound`] if the guild has no emoji with
+    /// the given name, or if the message was not sent in a guild.
+    ///
+    /// Otherwise returns the same errors as [`Self::react`].
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    pub async fn react_named(
+        &self,
+        cache_http: impl CacheHttp,
+        emoji_name: &str,
+    ) -> Result<Reaction> {
+        let guild_id = self.guild_id.ok_or(Error::Model(ModelError::EmojiNotFound))?;
+
+        // `None` if the guild is not cached, `Some(None)` if the cached guild
+        // has no such emoji.
+        #[allow(unused_mut)]
+        let mut cached: Option<Option<Emoji>> = None;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                cached = cache.guild_field(guild_id, |guild| {
+                    guild.emojis.values().find(|emoji| emoji.name == emoji_name).cloned()
+                });
+            }
+        }
+
+        let emoji = match cached {
+            Some(cached_emoji) => cached_emoji,
+            None => guild_id
+                .emojis(cache_http.http())
+                .await?
+                .into_iter()
+                .find(|emoji| emoji.name == emoji_name),
+        };
+
+        let emoji = emoji.ok_or(Error::Model(ModelError::EmojiNotFound))?;
+
+        self.react(cache_http, emoji).await
+    }
+
+    /// Removes the current user's reaction of the given type from the message.
+    ///
+    /// Unlike removing the reactions of other users, this does not require any
+    /// permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the request fails, for instance because the
+    /// message has been deleted.
+    pub async fn unreact(
+        &self,
+        cache_http: impl CacheHttp,
+        reaction_type: impl Into<ReactionType>,
+    ) -> Result<()> {
+        cache_http
+            .http()
+            .delete_reaction(self.channel_id.0, self.id.0, None, &reaction_type.into())
+            .await
+    }
+
     /// Uses Discord's inline reply to a user without pinging them.
     ///
     /// User mentions are generally around 20 or 21 characters long.
@@ -667,6 +2167,39 @@ impl Message {
         self._reply(cache_http, content, Some(false)).await
     }
 
+    /// Uses Discord's inline reply to a user without pinging them, and without
+    /// sending push or desktop notifications to anyone.
+    ///
+    /// This is useful to reply in a busy channel without disturbing its
+    /// members. Mentions in the content are still highlighted, as with
+    /// [`Self::reply`].
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    pub async fn reply_silent(
+        &self,
+        cache_http: impl CacheHttp,
+        content: impl Display,
+    ) -> Result<Message> {
+        self.reply_with(cache_http, |builder| {
+            reply_mentions(builder, false).suppress_notifications(true).content(content)
+        })
+        .await
+    }
+
     /// Uses Discord's inline reply to a user with a ping.
     ///
     /// **Note**: Requires the [Send Messages] permission.
@@ -693,6 +2226,154 @@ impl Message {
         self._reply(cache_http, content, Some(true)).await
     }
 
+    /// Uses Discord's inline reply to a user, leaving the allowed mentions of
+    /// the message untouched.
+    ///
+    /// Unlike [`Self::reply`] and [`Self::reply_ping`], which explicitly set
+    /// the allowed mentions, this only references the message. Discord's
+    /// defaults then apply, which matches a normal user reply: the replied
+    /// user is pinged, as is everyone mentioned in the content.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    pub async fn reply_plain(
+        &self,
+        cache_http: impl CacheHttp,
+        content: impl Display,
+    ) -> Result<Message> {
+        self.reply_with(cache_http, |builder| builder.content(content)).await
+    }
+
+    /// Uses Discord's inline reply to a user without pinging them, replying
+    /// with an embed instead of text content.
+    ///
+    /// Mentions in the embed are handled the same as with [`Self::reply`].
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::EmbedTooLarge`] if the embed is over the
+    /// maximum length, containing the number of unicode code points over the
+    /// limit.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    pub async fn reply_embed<F>(&self, cache_http: impl CacheHttp, f: F) -> Result<Message>
+    where
+        F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed,
+    {
+        self.reply_with(cache_http, |builder| reply_mentions(builder, false).embed(f)).await
+    }
+
+    /// Uses Discord's inline reply to a user, building the reply with a
+    /// [`CreateMessage`] for full control over its contents.
+    ///
+    /// The builder references this message before `f` is called; everything
+    /// else, including the allowed mentions, is left to `f`. Without allowed
+    /// mentions, Discord's defaults apply and the replied user is pinged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Message;
+    /// # async fn run(http: &Http, message: &Message) -> serenity::Result<()> {
+    /// message
+    ///     .reply_with(http, |m| {
+    ///         m.content("Done!")
+    ///             .embed(|e| e.title("Result"))
+    ///             .allowed_mentions(|am| am.replied_user(false))
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns the same errors as [`ChannelId::send_message`] otherwise.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    pub async fn reply_with<'a, F>(&self, cache_http: impl CacheHttp, f: F) -> Result<Message>
+    where
+        for<'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
+    {
+        check_send_permission(&cache_http, self.channel_id, self.guild_id)?;
+
+        self.channel_id
+            .send_message(cache_http.http(), |builder| f(builder.reference_message(self)))
+            .await
+    }
+
+    /// Uses Discord's inline reply to a user without pinging them, attaching
+    /// the given files and building the rest of the reply with `f`.
+    ///
+    /// Mentions are handled the same as with [`Self::reply`], unless `f` sets
+    /// its own allowed mentions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Message;
+    /// # async fn run(http: &Http, message: &Message, png: Vec<u8>) -> serenity::Result<()> {
+    /// let file = (png.as_slice(), "chart.png");
+    ///
+    /// message.reply_files(http, vec![file], |m| m.content("Here is your chart")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Note**: Requires the [Send Messages] and [Attach Files] permissions.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the [Send Messages] permission.
+    ///
+    /// Returns the same errors as [`ChannelId::send_message`] otherwise.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [Attach Files]: Permissions::ATTACH_FILES
+    pub async fn reply_files<'a, F, T, It>(
+        &self,
+        cache_http: impl CacheHttp,
+        files: It,
+        f: F,
+    ) -> Result<Message>
+    where
+        T: Into<AttachmentType<'a>>,
+        It: IntoIterator<Item = T>,
+        for<'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
+    {
+        self.reply_with(cache_http, |builder| f(reply_mentions(builder, false).add_files(files)))
+            .await
+    }
+
     /// Replies to the user, mentioning them prior to the content in the form
     /// of: `@<USER_ID> YOUR_CONTENT`.
     ///
@@ -722,6 +2403,69 @@ impl Message {
         self._reply(cache_http, format!("{} {}", self.author.mention(), content), None).await
     }
 
+    /// Returns a handle for sending multiple replies to this message, each
+    /// referencing it with consistent allowed mentions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // assuming a `message` has already been bound
+    ///
+    /// let reply = message.reply_context();
+    /// for page in pages {
+    ///     reply.send(&context, page).await?;
+    /// }
+    /// ```
+    #[must_use]
+    pub fn reply_context(&self) -> ReplyContext {
+        ReplyContext {
+            channel_id: self.channel_id,
+            guild_id: self.guild_id,
+            reference: MessageReference::from(self),
+            ping: false,
+        }
+    }
+
+    /// Replies to the message like [`Self::reply`], falling back to sending
+    /// the content to the author in a direct message if the current user is
+    /// not allowed to reply in the channel.
+    ///
+    /// The returned [`ReplyOrDm`] tells which of the two was done.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error other than missing permissions that replying
+    /// returned, or the error of sending the direct message if replying was
+    /// not permitted.
+    pub async fn reply_or_dm(
+        &self,
+        cache_http: impl CacheHttp,
+        content: impl Display,
+    ) -> Result<ReplyOrDm> {
+        let content = content.to_string();
+
+        let why = match self.reply(&cache_http, &content).await {
+            Ok(message) => return Ok(ReplyOrDm::Replied(message)),
+            Err(why) => why,
+        };
+
+        let forbidden = match &why {
+            Error::Model(ModelError::InvalidPermissions(_)) => true,
+            Error::Http(http_error) => http_error.status_code() == Some(StatusCode::FORBIDDEN),
+            _ => false,
+        };
+
+        if !forbidden {
+            return Err(why);
+        }
+
+        let message = self.author.direct_message(&cache_http, |m| m.content(content)).await?;
+
+        Ok(ReplyOrDm::DirectMessaged(message))
+    }
+
     /// `inlined` decides whether this reply is inlined and whether it pings.
     async fn _reply(
         &self,
@@ -729,39 +2473,22 @@ impl Message {
         content: impl Display,
         inlined: Option<bool>,
     ) -> Result<Message> {
-        #[cfg(feature = "cache")]
-        {
-            if let Some(cache) = cache_http.cache() {
-                if self.guild_id.is_some() {
-                    utils::user_has_perms_cache(
-                        cache,
-                        self.channel_id,
-                        self.guild_id,
-                        Permissions::SEND_MESSAGES,
-                    )?;
-                }
-            }
+        if let Some(ping_user) = inlined {
+            return self
+                .reply_with(cache_http, |builder| {
+                    reply_mentions(builder, ping_user).content(content)
+                })
+                .await;
         }
 
-        self.channel_id
-            .send_message(cache_http.http(), |builder| {
-                if let Some(ping_user) = inlined {
-                    builder.reference_message(self).allowed_mentions(|f| {
-                        f.replied_user(ping_user)
-                            // By providing allowed_mentions, Discord disabled _all_ pings by
-                            // default so we need to re-enable them
-                            .parse(crate::builder::ParseValue::Everyone)
-                            .parse(crate::builder::ParseValue::Users)
-                            .parse(crate::builder::ParseValue::Roles)
-                    });
-                }
+        check_send_permission(&cache_http, self.channel_id, self.guild_id)?;
 
-                builder.content(content)
-            })
-            .await
+        self.channel_id.send_message(cache_http.http(), |builder| builder.content(content)).await
     }
 
-    /// Delete all embeds in this message
+    /// Hides all embeds of this message, or shows them again if `suppress` is
+    /// `false`, by toggling the [`MessageFlags::SUPPRESS_EMBEDS`] flag.
+    ///
     /// **Note**: The logged in user must either be the author of the message or
     /// have the [Manage Messages] permission.
     ///
@@ -774,7 +2501,11 @@ impl Message {
     /// Otherwise returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
-    pub async fn suppress_embeds(&mut self, cache_http: impl CacheHttp) -> Result<()> {
+    pub async fn suppress_embeds(
+        &mut self,
+        cache_http: impl CacheHttp,
+        suppress: bool,
+    ) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -791,10 +2522,10 @@ impl Message {
             }
         }
 
-        let mut suppress = EditMessage::default();
-        suppress.suppress_embeds(true);
+        let mut edit = EditMessage::default();
+        edit.suppress_embeds(suppress);
 
-        let map = json::hashmap_to_json_map(suppress.0);
+        let map = json::hashmap_to_json_map(edit.0);
 
         *self =
             cache_http.http().edit_message(self.channel_id.0, self.id.0, &Value::from(map)).await?;
@@ -812,8 +2543,31 @@ impl Message {
     /// Checks whether the message mentions passed [`User`].
     #[inline]
     #[must_use]
-    pub fn mentions_user(&self, user: &User) -> bool {
-        self.mentions_user_id(user.id)
+    pub fn mentions_user(&self, user: &User) -> bool {
+        self.mentions_user_id(user.id)
+    }
+
+    /// Checks whether the message mentions the passed [`RoleId`].
+    #[inline]
+    pub fn mentions_role(&self, id: impl Into<RoleId>) -> bool {
+        self.mention_roles.contains(&id.into())
+    }
+
+    /// Checks whether the message mentions `@everyone` or `@here`, even if it
+    /// did not actually ping anyone.
+    ///
+    /// [`Self::mention_everyone`] is only set if the author was allowed to
+    /// ping everyone, so this additionally looks for the literal mentions in
+    /// the content, outside of code blocks and inline code. This is useful for
+    /// filtering attempted mass pings.
+    #[must_use]
+    pub fn mentions_everyone_effective(&self) -> bool {
+        if self.mention_everyone {
+            return true;
+        }
+
+        let content = strip_code(&self.content);
+        content.contains("@everyone") || content.contains("@here")
     }
 
     /// Checks whether the message mentions the current user.
@@ -845,22 +2599,45 @@ impl Message {
     /// the required permissions.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
     pub async fn unpin(&self, cache_http: impl CacheHttp) -> Result<()> {
-        #[cfg(feature = "cache")]
-        {
-            if let Some(cache) = cache_http.cache() {
-                if self.guild_id.is_some() {
-                    utils::user_has_perms_cache(
-                        cache,
-                        self.channel_id,
-                        self.guild_id,
-                        Permissions::MANAGE_MESSAGES,
-                    )?;
-                }
-            }
+        self.set_pinned(cache_http, false, None).await
+    }
+
+    /// Unpins the message from its channel, attaching a `reason` to the entry
+    /// in the audit log.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::unpin`].
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
+    pub async fn unpin_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self.set_pinned(cache_http, false, Some(reason)).await
+    }
+
+    /// Unpins this message like [`Self::unpin`], unless [`Self::pinned`] says
+    /// it is not pinned, in which case no request is made.
+    ///
+    /// Returns whether the message was unpinned by this call.
+    ///
+    /// **Note**: [`Self::pinned`] is not updated, and is only as recent as
+    /// this copy of the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::unpin`].
+    pub async fn unpin_if_pinned(&self, cache_http: impl CacheHttp) -> Result<bool> {
+        if !self.pinned {
+            return Ok(false);
         }
 
-        cache_http.http().unpin_message(self.channel_id.0, self.id.0, None).await
+        self.set_pinned(cache_http, false, None).await?;
+
+        Ok(true)
     }
 
     /// Tries to return author's nickname in the current channel's guild.
@@ -871,6 +2648,85 @@ impl Message {
         self.author.nick_in(cache_http, self.guild_id?).await
     }
 
+    /// Returns the "tag" of the message's author, for use in logging.
+    ///
+    /// This is the same as [`User::tag`], except that users who migrated to
+    /// Discord's new username system (with a discriminator of `0`) are
+    /// represented by their unique username alone.
+    #[must_use]
+    pub fn author_tag(&self) -> String {
+        if self.author.discriminator == 0 {
+            self.author.name.clone()
+        } else {
+            self.author.tag()
+        }
+    }
+
+    /// Returns the URL of the author's avatar, falling back to their default
+    /// avatar if they have none set.
+    ///
+    /// This is equivalent to [`Self::author_face`], and is what is usually
+    /// shown as the author icon of an embed quoting this message.
+    #[inline]
+    #[must_use]
+    pub fn author_avatar_url(&self) -> String {
+        self.author_face()
+    }
+
+    /// Returns the URL of the author's avatar, falling back to their default
+    /// avatar if needed.
+    ///
+    /// See [`User::face`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn author_face(&self) -> String {
+        self.author.face()
+    }
+
+    /// Parses the details of the triggered rule from an auto moderation system
+    /// message.
+    ///
+    /// Returns [`None`] if the message is not of the
+    /// [`MessageType::AutoModerationAction`] kind or carries no embed. Fields
+    /// that are absent from the embed are left as [`None`].
+    #[must_use]
+    pub fn automod_action(&self) -> Option<AutoModActionInfo> {
+        if self.kind != MessageType::AutoModerationAction {
+            return None;
+        }
+
+        let embed = self.embeds.first()?;
+        let field = |name: &str| embed.field_value(name).map(ToString::to_string);
+
+        Some(AutoModActionInfo {
+            rule_name: field("rule_name"),
+            channel_id: embed
+                .field_value("channel_id")
+                .and_then(|id| id.parse().ok())
+                .map(ChannelId),
+            content: embed.description.clone(),
+            matched_keyword: field("keyword"),
+            matched_content: field("keyword_matched_content"),
+        })
+    }
+
+    /// Returns the integration type of the application installation that
+    /// authorized the interaction this message was created from.
+    ///
+    /// This is `0` for guild-installed and `1` for user-installed applications,
+    /// and is derived from the `authorizing_integration_owners` of
+    /// [`Self::interaction_metadata`]. If the application is installed in both
+    /// contexts, the guild installation takes precedence.
+    ///
+    /// Returns [`None`] if the message was not created from an interaction, or
+    /// if Discord did not send the integration owners.
+    #[must_use]
+    pub fn application_integration_type(&self) -> Option<u8> {
+        let owners = &self.interaction_metadata.as_ref()?.authorizing_integration_owners;
+
+        owners.keys().filter_map(|kind| kind.parse().ok()).min()
+    }
+
     /// Returns a link referencing this message. When clicked, users will jump to the message.
     /// The link will be valid for messages in either private channels or guilds.
     #[inline]
@@ -879,6 +2735,69 @@ impl Message {
         self.id.link(self.channel_id, self.guild_id)
     }
 
+    /// Parses a link to a message, as created by [`Self::link`], into its
+    /// parts.
+    ///
+    /// Both links to guild messages and to private messages (`@me`) are
+    /// accepted, including those of the `ptb` and `canary` clients.
+    ///
+    /// Returns [`None`] if the URL is not a message link or contains invalid
+    /// IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::channel::Message;
+    /// use serenity::model::id::{ChannelId, GuildId, MessageId};
+    ///
+    /// let link = Message::parse_link("https://discord.com/channels/1/2/3").unwrap();
+    /// assert_eq!(link.guild_id, Some(GuildId(1)));
+    /// assert_eq!(link.channel_id, ChannelId(2));
+    /// assert_eq!(link.message_id, MessageId(3));
+    ///
+    /// assert!(Message::parse_link("https://discord.com/channels/@me/2/3/").is_some());
+    /// assert!(Message::parse_link("https://discord.com/channels/1/2/x").is_none());
+    /// ```
+    #[must_use]
+    pub fn parse_link(url: &str) -> Option<MessageLink> {
+        let url = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))?;
+        let (host, path) = url.split_once('/')?;
+        if !matches!(
+            host,
+            "discord.com"
+                | "discordapp.com"
+                | "ptb.discord.com"
+                | "canary.discord.com"
+                | "ptb.discordapp.com"
+                | "canary.discordapp.com"
+        ) {
+            return None;
+        }
+
+        let path = path.strip_suffix('/').unwrap_or(path);
+        let mut segments = path.split('/');
+        if segments.next()? != "channels" {
+            return None;
+        }
+
+        let guild_id = match segments.next()? {
+            "@me" => None,
+            id => Some(GuildId(id.parse().ok()?)),
+        };
+        let channel_id = ChannelId(segments.next()?.parse().ok()?);
+        let message_id = MessageId(segments.next()?.parse().ok()?);
+
+        if segments.next().is_some() {
+            return None;
+        }
+
+        Some(MessageLink {
+            guild_id,
+            channel_id,
+            message_id,
+        })
+    }
+
     /// Same as [`Self::link`] but tries to find the [`GuildId`]
     /// if Discord does not provide it.
     ///
@@ -888,6 +2807,45 @@ impl Message {
         self.id.link_ensured(cache_http, self.channel_id, self.guild_id).await
     }
 
+    /// Returns a name and value for an embed field quoting this message, as
+    /// commonly used for "reply context" embeds.
+    ///
+    /// The name is of the form `Reply to {author}`, using [`Self::author_tag`].
+    /// The value is a preview of the content, shortened to at most `max_len`
+    /// unicode code points with a trailing `…` if it was cut, followed by a
+    /// `[jump to message]` link built with [`Self::link`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // assuming a `message` has already been bound
+    ///
+    /// let (name, value) = message.as_quote_field(100);
+    /// channel_id.send_message(&http, |m| m.embed(|e| e.field(name, value, false))).await?;
+    /// ```
+    #[must_use]
+    pub fn as_quote_field(&self, max_len: usize) -> (String, String) {
+        let name = format!("Reply to {}", self.author_tag());
+
+        let mut value = if self.content.chars().count() > max_len {
+            let mut preview: String =
+                self.content.chars().take(max_len.saturating_sub(1)).collect();
+            preview.push('\u{2026}');
+            preview
+        } else {
+            self.content.clone()
+        };
+
+        if !value.is_empty() {
+            value.push('\n');
+        }
+        value.push_str("[jump to message](");
+        value.push_str(&self.link());
+        value.push(')');
+
+        (name, value)
+    }
+
     /// Await a single reaction on this message.
     #[cfg(feature = "collector")]
     pub fn await_reaction(&self, shard_messenger: impl AsRef<ShardMessenger>) -> CollectReaction {
@@ -974,79 +2932,474 @@ impl Message {
         }
 
         for embed in embeds {
-            let mut total: usize = 0;
-
-            if let Some(&Value::Object(ref author)) = embed.get("author") {
-                if let Some(&Value::Object(ref name)) = author.get("name") {
-                    total += name.len();
+            if let Some(Value::Array(fields)) = embed.get("fields") {
+                if fields.len() > constants::EMBED_MAX_FIELD_COUNT {
+                    return Err(Error::Model(ModelError::EmbedFieldAmount));
                 }
             }
 
-            if let Some(&Value::String(ref description)) = embed.get("description") {
-                total += description.len();
+            let parts = embed_text_parts(|key| embed.get(key));
+            check_text_parts(&parts)?;
+
+            let total: usize = parts.iter().map(|(_, text)| text.chars().count()).sum();
+
+            if total > constants::EMBED_MAX_LENGTH {
+                let overflow = total - constants::EMBED_MAX_LENGTH;
+                return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
             }
+        }
 
-            if let Some(&Value::Array(ref fields)) = embed.get("fields") {
-                for field_as_value in fields {
-                    if let Value::Object(ref field) = *field_as_value {
-                        if let Some(&Value::String(ref field_name)) = field.get("name") {
-                            total += field_name.len();
-                        }
+        Ok(())
+    }
 
-                        if let Some(&Value::String(ref field_value)) = field.get("value") {
-                            total += field_value.len();
-                        }
-                    }
-                }
+    pub(crate) fn check_sticker_ids_length(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(sticker_ids)) = map.get("sticker_ids") {
+            if sticker_ids.len() > constants::STICKER_MAX_COUNT {
+                return Err(Error::Model(ModelError::StickerAmount));
             }
+        }
+
+        Ok(())
+    }
+}
+
+/// Lowercases the text and strips the zero-width characters from it.
+#[cfg(feature = "model")]
+fn normalize_words(text: &str) -> String {
+    text.chars()
+        .filter(|c| !matches!(c, '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Removes code blocks and inline code from the text.
+#[cfg(feature = "model")]
+fn strip_code(text: &str) -> String {
+    text.split("```")
+        .step_by(2)
+        .flat_map(|outside_block| outside_block.split('`').step_by(2))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits `content` into tokens, paired with the code point offset they start
+/// at.
+#[cfg(feature = "model")]
+fn tokenize_content(content: &str) -> Vec<(usize, ContentToken)> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut text_start = 0;
+    let mut offset = 0;
+    let mut rest = content;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '<' {
+            let markup = rest.find('>').map(|end| &rest[..=end]);
 
-            if let Some(&Value::Object(ref footer)) = embed.get("footer") {
-                if let Some(&Value::String(ref text)) = footer.get("text") {
-                    total += text.len();
+            if let Some((markup, token)) = markup.and_then(|m| Some((m, parse_content_token(m)?))) {
+                if !text.is_empty() {
+                    tokens.push((text_start, ContentToken::Text(std::mem::take(&mut text))));
                 }
-            }
 
-            if let Some(&Value::String(ref title)) = embed.get("title") {
-                total += title.len();
+                tokens.push((offset, token));
+                offset += markup.chars().count();
+                rest = &rest[markup.len()..];
+
+                continue;
             }
+        }
+
+        if text.is_empty() {
+            text_start = offset;
+        }
+
+        text.push(c);
+        offset += 1;
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if !text.is_empty() {
+        tokens.push((text_start, ContentToken::Text(text)));
+    }
+
+    tokens
+}
+
+/// Parses a single piece of markup enclosed in angle brackets, such as
+/// `<@123>` or `<t:1462015105:R>`.
+#[cfg(feature = "model")]
+fn parse_content_token(markup: &str) -> Option<ContentToken> {
+    fn parse_id(id: &str) -> Option<u64> {
+        if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        id.parse().ok()
+    }
+
+    let inner = markup.strip_prefix('<')?.strip_suffix('>')?;
+
+    if let Some(id) = inner.strip_prefix("@&") {
+        parse_id(id).map(|id| ContentToken::RoleMention(RoleId(id)))
+    } else if let Some(id) = inner.strip_prefix('@') {
+        let id = id.strip_prefix('!').unwrap_or(id);
+
+        parse_id(id).map(|id| ContentToken::UserMention(UserId(id)))
+    } else if let Some(id) = inner.strip_prefix('#') {
+        parse_id(id).map(|id| ContentToken::ChannelMention(ChannelId(id)))
+    } else if let Some(timestamp) = inner.strip_prefix("t:") {
+        let (unix, style) = match timestamp.split_once(':') {
+            Some((unix, style)) => {
+                let mut chars = style.chars();
+                let style = chars.next().filter(|c| "tTdDfFR".contains(*c))?;
+
+                if chars.next().is_some() {
+                    return None;
+                }
+
+                (unix, Some(style))
+            },
+            None => (timestamp, None),
+        };
+
+        let digits = unix.strip_prefix('-').unwrap_or(unix);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(ContentToken::Timestamp {
+            unix: unix.parse().ok()?,
+            style,
+        })
+    } else {
+        let (animated, emoji) = match inner.strip_prefix("a:") {
+            Some(emoji) => (true, emoji),
+            None => (false, inner.strip_prefix(':')?),
+        };
+
+        let (name, id) = emoji.split_once(':')?;
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+
+        Some(ContentToken::CustomEmoji {
+            id: EmojiId(parse_id(id)?),
+            name: name.to_string(),
+            animated,
+        })
+    }
+}
+
+/// Returns whether `word` occurs in `text` without being surrounded by other
+/// alphanumeric characters.
+#[cfg(feature = "model")]
+fn contains_word(text: &str, word: &str) -> bool {
+    if word.is_empty() {
+        return false;
+    }
+
+    text.match_indices(word).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + word.len()..].chars().next();
+
+        !matches!(before, Some(c) if c.is_alphanumeric())
+            && !matches!(after, Some(c) if c.is_alphanumeric())
+    })
+}
+
+/// Compares two reactions by their emoji only, as gateway events may omit the
+/// name or animated flag of a custom emoji.
+#[cfg(feature = "model")]
+fn reaction_type_matches(a: &ReactionType, b: &ReactionType) -> bool {
+    match (a, b) {
+        (
+            ReactionType::Custom {
+                id: a, ..
+            },
+            ReactionType::Custom {
+                id: b, ..
+            },
+        ) => a == b,
+        _ => a == b,
+    }
+}
+
+impl AsRef<MessageId> for Message {
+    fn as_ref(&self) -> &MessageId {
+        &self.id
+    }
+}
+
+impl From<Message> for MessageId {
+    /// Gets the Id of a [`Message`].
+    fn from(message: Message) -> MessageId {
+        message.id
+    }
+}
+
+impl<'a> From<&'a Message> for MessageId {
+    /// Gets the Id of a [`Message`].
+    fn from(message: &Message) -> MessageId {
+        message.id
+    }
+}
+
+/// The kind of channel a message was sent in, as returned by
+/// [`Message::origin`].
+#[cfg(feature = "model")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MessageOrigin {
+    /// A direct message channel with a single user.
+    Dm,
+    /// A text, news or directory channel of a guild.
+    GuildText,
+    /// A public, private or news thread of a guild.
+    GuildThread,
+    /// The text chat of a voice or stage channel of a guild.
+    GuildVoice,
+    /// A direct message channel with multiple users.
+    ///
+    /// **Note**: Bots cannot be in group direct messages, so this is not
+    /// returned by [`Message::origin`].
+    Group,
+    /// The channel is not cached, or is of an unknown kind.
+    Unknown,
+}
+
+#[cfg(feature = "model")]
+impl MessageOrigin {
+    /// Returns the origin of a message sent in a channel of the given kind.
+    #[must_use]
+    pub fn from_channel_type(kind: ChannelType) -> Self {
+        match kind {
+            ChannelType::Text | ChannelType::News | ChannelType::Directory => Self::GuildText,
+            ChannelType::NewsThread | ChannelType::PublicThread | ChannelType::PrivateThread => {
+                Self::GuildThread
+            },
+            ChannelType::Voice | ChannelType::Stage => Self::GuildVoice,
+            ChannelType::Private => Self::Dm,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// True if the message was sent in a thread.
+    #[inline]
+    #[must_use]
+    pub fn is_thread(self) -> bool {
+        self == Self::GuildThread
+    }
+
+    /// True if the message was sent in a channel of a guild.
+    #[must_use]
+    pub fn is_guild(self) -> bool {
+        matches!(self, Self::GuildText | Self::GuildThread | Self::GuildVoice)
+    }
+}
+
+/// The outcome of [`Message::reply_or_dm`].
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ReplyOrDm {
+    /// The message was replied to in its channel.
+    Replied(Message),
+    /// Replying was not permitted, so the author was sent a direct message
+    /// instead.
+    DirectMessaged(Message),
+}
+
+#[cfg(feature = "model")]
+impl ReplyOrDm {
+    /// Returns the message that was sent, regardless of where it was sent.
+    #[must_use]
+    pub fn message(&self) -> &Message {
+        match self {
+            Self::Replied(message) | Self::DirectMessaged(message) => message,
+        }
+    }
+
+    /// Consumes the outcome, returning the message that was sent.
+    #[must_use]
+    pub fn into_message(self) -> Message {
+        match self {
+            Self::Replied(message) | Self::DirectMessaged(message) => message,
+        }
+    }
+}
+
+/// The error of [`Message::react_all`], returned when adding one of the
+/// reactions failed.
+#[cfg(feature = "model")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ReactAllError {
+    /// The reactions that were added before the failure, in order.
+    pub added: Vec<Reaction>,
+    /// The error that stopped adding the remaining reactions.
+    pub error: Error,
+}
+
+#[cfg(feature = "model")]
+impl fmt::Display for ReactAllError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "model")]
+impl StdError for ReactAllError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "model")]
+impl From<ReactAllError> for Error {
+    fn from(error: ReactAllError) -> Error {
+        error.error
+    }
+}
+
+/// The parts of a link to a [`Message`], as parsed by [`Message::parse_link`].
+#[cfg(feature = "model")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct MessageLink {
+    /// The ID of the guild the message was sent in, or [`None`] for private
+    /// messages.
+    pub guild_id: Option<GuildId>,
+    /// The ID of the channel the message was sent in.
+    pub channel_id: ChannelId,
+    /// The ID of the message.
+    pub message_id: MessageId,
+}
+
+/// A piece of a message's content, as returned by [`Message::parse_content`].
+#[cfg(feature = "model")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ContentToken {
+    /// Plain text between other tokens, including any markdown.
+    Text(String),
+    /// A user mention, such as `<@123>` or `<@!123>`.
+    UserMention(UserId),
+    /// A role mention, such as `<@&123>`.
+    RoleMention(RoleId),
+    /// A channel mention, such as `<#123>`.
+    ChannelMention(ChannelId),
+    /// A custom emoji, such as `<:name:123>` or `<a:name:123>`.
+    CustomEmoji {
+        /// The ID of the emoji.
+        id: EmojiId,
+        /// The name of the emoji.
+        name: String,
+        /// Whether the emoji is animated.
+        animated: bool,
+    },
+    /// A timestamp, such as `<t:1462015105>` or `<t:1462015105:R>`.
+    Timestamp {
+        /// The unix timestamp, in seconds.
+        unix: i64,
+        /// The letter of the display style, such as `R` for relative time, if
+        /// one was given.
+        style: Option<char>,
+    },
+}
 
-            if total > constants::EMBED_MAX_LENGTH {
-                let overflow = total - constants::EMBED_MAX_LENGTH;
-                return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
+/// Checks whether the current user may send messages in a guild channel, if
+/// the cache is available.
+#[cfg(feature = "model")]
+fn check_send_permission(
+    #[allow(unused_variables)] cache_http: &impl CacheHttp,
+    #[allow(unused_variables)] channel_id: ChannelId,
+    #[allow(unused_variables)] guild_id: Option<GuildId>,
+) -> Result<()> {
+    #[cfg(feature = "cache")]
+    {
+        if let Some(cache) = cache_http.cache() {
+            if guild_id.is_some() {
+                utils::user_has_perms_cache(
+                    cache,
+                    channel_id,
+                    guild_id,
+                    Permissions::SEND_MESSAGES,
+                )?;
             }
         }
-
-        Ok(())
     }
 
-    pub(crate) fn check_sticker_ids_length(map: &JsonMap) -> Result<()> {
-        if let Some(Value::Array(sticker_ids)) = map.get("sticker_ids") {
-            if sticker_ids.len() > constants::STICKER_MAX_COUNT {
-                return Err(Error::Model(ModelError::StickerAmount));
-            }
-        }
+    Ok(())
+}
 
-        Ok(())
-    }
+/// Sets the allowed mentions of an inline reply, pinging the replied user
+/// only if `ping_user` is set.
+#[cfg(feature = "model")]
+fn reply_mentions<'a, 'b>(
+    builder: &'b mut CreateMessage<'a>,
+    ping_user: bool,
+) -> &'b mut CreateMessage<'a> {
+    builder.allowed_mentions(|f| {
+        f.replied_user(ping_user)
+            // By providing allowed_mentions, Discord disabled _all_ pings by
+            // default so we need to re-enable them
+            .parse(crate::builder::ParseValue::Everyone)
+            .parse(crate::builder::ParseValue::Users)
+            .parse(crate::builder::ParseValue::Roles)
+    })
 }
 
-impl AsRef<MessageId> for Message {
-    fn as_ref(&self) -> &MessageId {
-        &self.id
-    }
+/// A reusable handle for sending multiple replies to the same [`Message`],
+/// created with [`Message::reply_context`].
+///
+/// Every message sent through it references the original message and uses the
+/// same allowed mentions as [`Message::reply`], or [`Message::reply_ping`] if
+/// [`Self::ping`] is enabled.
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct ReplyContext {
+    channel_id: ChannelId,
+    guild_id: Option<GuildId>,
+    reference: MessageReference,
+    ping: bool,
 }
 
-impl From<Message> for MessageId {
-    /// Gets the Id of a [`Message`].
-    fn from(message: Message) -> MessageId {
-        message.id
+#[cfg(feature = "model")]
+impl ReplyContext {
+    /// Sets whether the replies ping the author of the original message.
+    ///
+    /// Defaults to `false`.
+    pub fn ping(&mut self, ping: bool) -> &mut Self {
+        self.ping = ping;
+        self
     }
-}
 
-impl<'a> From<&'a Message> for MessageId {
-    /// Gets the Id of a [`Message`].
-    fn from(message: &Message) -> MessageId {
-        message.id
+    /// Sends a message replying to the original message.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the above limit, containing the number of unicode code points
+    /// over the limit.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    pub async fn send(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
+        check_send_permission(&cache_http, self.channel_id, self.guild_id)?;
+
+        self.channel_id
+            .send_message(cache_http.http(), |builder| {
+                reply_mentions(builder.reference_message(self.reference.clone()), self.ping)
+                    .content(content)
+            })
+            .await
     }
 }
 
@@ -1058,103 +3411,191 @@ impl<'a> From<&'a Message> for MessageId {
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#reaction-object).
 ///
 /// [reaction type]: ReactionType
-#[derive(Clone, Debug, Deserialize, Serialize)]
+///
+/// **Note**: [`Self::count`] and [`Self::me`] are only meaningful when received
+/// from Discord, and are omitted when serializing if they hold their default
+/// values.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 pub struct MessageReaction {
     /// The amount of the type of reaction that have been sent for the
     /// associated message.
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub count: u64,
     /// Indicator of whether the current user has sent the type of reaction.
+    #[serde(default, skip_serializing_if = "is_false")]
     pub me: bool,
     /// The type of reaction.
     #[serde(rename = "emoji")]
     pub reaction_type: ReactionType,
 }
 
-/// Differentiates between regular and different types of system messages.
+/// The nonce of a [`Message`], which Discord sends either as a string or as an
+/// integer.
 ///
-/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object-message-types).
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
 #[non_exhaustive]
-pub enum MessageType {
-    /// A regular message.
-    Regular = 0,
-    /// An indicator that a recipient was added by the author.
-    GroupRecipientAddition = 1,
-    /// An indicator that a recipient was removed by the author.
-    GroupRecipientRemoval = 2,
-    /// An indicator that a call was started by the author.
-    GroupCallCreation = 3,
-    /// An indicator that the group name was modified by the author.
-    GroupNameUpdate = 4,
-    /// An indicator that the group icon was modified by the author.
-    GroupIconUpdate = 5,
-    /// An indicator that a message was pinned by the author.
-    PinsAdd = 6,
-    /// An indicator that a member joined the guild.
-    MemberJoin = 7,
-    /// An indicator that someone has boosted the guild.
-    NitroBoost = 8,
-    /// An indicator that the guild has reached nitro tier 1
-    NitroTier1 = 9,
-    /// An indicator that the guild has reached nitro tier 2
-    NitroTier2 = 10,
-    /// An indicator that the guild has reached nitro tier 3
-    NitroTier3 = 11,
-    /// An indicator that the channel is now following a news channel
-    ChannelFollowAdd = 12,
-    /// An indicator that the guild is disqualified for Discovery Feature
-    GuildDiscoveryDisqualified = 14,
-    /// An indicator that the guild is requalified for Discovery Feature
-    GuildDiscoveryRequalified = 15,
-    /// The first warning before guild discovery removal.
-    GuildDiscoveryGracePeriodInitialWarning = 16,
-    /// The last warning before guild discovery removal.
-    GuildDiscoveryGracePeriodFinalWarning = 17,
-    /// Message sent to inform users that a thread was created.
-    ThreadCreated = 18,
-    /// A message reply.
-    InlineReply = 19,
-    /// A slash command.
-    ChatInputCommand = 20,
-    /// A thread start message.
-    ThreadStarterMessage = 21,
-    /// Server setup tips.
-    GuildInviteReminder = 22,
-    /// A context menu command.
-    ContextMenuCommand = 23,
-    /// A message from an auto moderation action.
-    AutoModerationAction = 24,
-    /// An indicator that the message is of unknown type.
-    Unknown = !0,
+pub enum MessageNonce {
+    /// A nonce sent as a string.
+    String(String),
+    /// A nonce sent as an integer.
+    Number(u64),
 }
 
-enum_number!(MessageType {
-    Regular,
-    GroupRecipientAddition,
-    GroupRecipientRemoval,
-    GroupCallCreation,
-    GroupNameUpdate,
-    GroupIconUpdate,
-    PinsAdd,
-    MemberJoin,
-    NitroBoost,
-    NitroTier1,
-    NitroTier2,
-    NitroTier3,
-    ChannelFollowAdd,
-    GuildDiscoveryDisqualified,
-    GuildDiscoveryRequalified,
-    GuildDiscoveryGracePeriodInitialWarning,
-    GuildDiscoveryGracePeriodFinalWarning
-    ThreadCreated,
-    InlineReply,
-    ChatInputCommand,
-    ThreadStarterMessage,
-    GuildInviteReminder,
-    ContextMenuCommand,
-    AutoModerationAction,
-});
+impl fmt::Display for MessageNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(nonce) => f.write_str(nonce),
+            Self::Number(nonce) => fmt::Display::fmt(nonce, f),
+        }
+    }
+}
+
+enum_number! {
+    /// Differentiates between regular and different types of system messages.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object-message-types).
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum MessageType {
+        /// A regular message.
+        Regular = 0,
+        /// An indicator that a recipient was added by the author.
+        GroupRecipientAddition = 1,
+        /// An indicator that a recipient was removed by the author.
+        GroupRecipientRemoval = 2,
+        /// An indicator that a call was started by the author.
+        GroupCallCreation = 3,
+        /// An indicator that the group name was modified by the author.
+        GroupNameUpdate = 4,
+        /// An indicator that the group icon was modified by the author.
+        GroupIconUpdate = 5,
+        /// An indicator that a message was pinned by the author.
+        PinsAdd = 6,
+        /// An indicator that a member joined the guild.
+        MemberJoin = 7,
+        /// An indicator that someone has boosted the guild.
+        NitroBoost = 8,
+        /// An indicator that the guild has reached nitro tier 1
+        NitroTier1 = 9,
+        /// An indicator that the guild has reached nitro tier 2
+        NitroTier2 = 10,
+        /// An indicator that the guild has reached nitro tier 3
+        NitroTier3 = 11,
+        /// An indicator that the channel is now following a news channel
+        ChannelFollowAdd = 12,
+        /// An indicator that the guild is disqualified for Discovery Feature
+        GuildDiscoveryDisqualified = 14,
+        /// An indicator that the guild is requalified for Discovery Feature
+        GuildDiscoveryRequalified = 15,
+        /// The first warning before guild discovery removal.
+        GuildDiscoveryGracePeriodInitialWarning = 16,
+        /// The last warning before guild discovery removal.
+        GuildDiscoveryGracePeriodFinalWarning = 17,
+        /// Message sent to inform users that a thread was created.
+        ThreadCreated = 18,
+        /// A message reply.
+        InlineReply = 19,
+        /// A slash command.
+        ChatInputCommand = 20,
+        /// A thread start message.
+        ThreadStarterMessage = 21,
+        /// Server setup tips.
+        GuildInviteReminder = 22,
+        /// A context menu command.
+        ContextMenuCommand = 23,
+        /// A message from an auto moderation action.
+        AutoModerationAction = 24,
+        _ => Unknown(u64),
+    }
+}
+
+impl MessageType {
+    /// Returns a human-readable name of the message type, such as
+    /// `"Member Join"` or `"Nitro Boost"`.
+    ///
+    /// This is also what the [`Display`] implementation writes, except for
+    /// message types unknown to the library: those are labelled `"Unknown"`
+    /// here, while [`Display`] also writes their raw value, e.g. `Unknown (99)`.
+    ///
+    /// [`Display`]: fmt::Display
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Regular => "Regular",
+            Self::GroupRecipientAddition => "Recipient Added",
+            Self::GroupRecipientRemoval => "Recipient Removed",
+            Self::GroupCallCreation => "Call Started",
+            Self::GroupNameUpdate => "Name Changed",
+            Self::GroupIconUpdate => "Icon Changed",
+            Self::PinsAdd => "Message Pinned",
+            Self::MemberJoin => "Member Join",
+            Self::NitroBoost => "Nitro Boost",
+            Self::NitroTier1 => "Nitro Tier 1",
+            Self::NitroTier2 => "Nitro Tier 2",
+            Self::NitroTier3 => "Nitro Tier 3",
+            Self::ChannelFollowAdd => "Channel Followed",
+            Self::GuildDiscoveryDisqualified => "Discovery Disqualified",
+            Self::GuildDiscoveryRequalified => "Discovery Requalified",
+            Self::GuildDiscoveryGracePeriodInitialWarning => {
+                "Discovery Grace Period Initial Warning"
+            },
+            Self::GuildDiscoveryGracePeriodFinalWarning => "Discovery Grace Period Final Warning",
+            Self::ThreadCreated => "Thread Created",
+            Self::InlineReply => "Reply",
+            Self::ChatInputCommand => "Slash Command",
+            Self::ThreadStarterMessage => "Thread Starter Message",
+            Self::GuildInviteReminder => "Invite Reminder",
+            Self::ContextMenuCommand => "Context Menu Command",
+            Self::AutoModerationAction => "Auto Moderation Action",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+
+    /// True if the type is that of a system message, such as a member join or
+    /// a pin notification, rather than one authored by a user or application.
+    #[must_use]
+    pub fn is_system(&self) -> bool {
+        !matches!(
+            self,
+            Self::Regular | Self::InlineReply | Self::ChatInputCommand | Self::ContextMenuCommand
+        )
+    }
+
+    /// True if messages of this type can be deleted through the API.
+    ///
+    /// Group DM recipient, name and icon changes as well as thread starter
+    /// messages can never be deleted. Unknown types are assumed not to be
+    /// deletable.
+    ///
+    /// **Note**: Deleting a message of another user still requires the
+    /// [Manage Messages] permission.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[must_use]
+    pub fn is_deletable(&self) -> bool {
+        !matches!(
+            self,
+            Self::GroupRecipientAddition
+                | Self::GroupRecipientRemoval
+                | Self::GroupNameUpdate
+                | Self::GroupIconUpdate
+                | Self::ThreadStarterMessage
+                | Self::Unknown(_)
+        )
+    }
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(n) => write!(f, "Unknown ({})", n),
+            _ => f.write_str(self.label()),
+        }
+    }
+}
 
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object-message-activity-types).
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -1178,6 +3619,23 @@ enum_number!(MessageActivityKind {
     JOIN_REQUEST
 });
 
+impl MessageActivityKind {
+    /// Returns the name Discord uses for the activity type, such as `"join"`
+    /// or `"join_request"`.
+    ///
+    /// Activity types not known to the library are named `"unknown"`.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::JOIN => "join",
+            Self::SPECTATE => "spectate",
+            Self::LISTEN => "listen",
+            Self::JOIN_REQUEST => "join_request",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
 /// Rich Presence application information.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/application#application-object).
@@ -1207,6 +3665,15 @@ pub struct MessageActivity {
     pub kind: MessageActivityKind,
     /// `party_id` from a Rich Presence event.
     pub party_id: Option<String>,
+    /// The Id of the Rich Presence session the invite was sent from.
+    ///
+    /// This is undocumented by Discord, and only sent with game invites.
+    ///
+    /// **Note**: The join and spectate secrets of the activity themselves are
+    /// never sent with the message; they are only passed to the game client of
+    /// the user accepting the invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 /// Reference data sent with crossposted messages.
@@ -1223,6 +3690,18 @@ pub struct MessageReference {
     pub guild_id: Option<GuildId>,
 }
 
+#[cfg(feature = "model")]
+impl MessageReference {
+    /// Returns a link to the referenced message, like [`MessageId::link`].
+    ///
+    /// Returns [`None`] if the reference does not include a message, such as
+    /// the reference of a channel follow notification.
+    #[must_use]
+    pub fn link(&self) -> Option<String> {
+        self.message_id.map(|message_id| message_id.link(self.channel_id, self.guild_id))
+    }
+}
+
 impl From<&Message> for MessageReference {
     fn from(m: &Message) -> Self {
         Self {
@@ -1281,6 +3760,8 @@ bitflags! {
         const LOADING = 1 << 7;
         /// This message failed to mention some roles and add their members to the thread.
         const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        /// This message will not trigger push and desktop notifications.
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
     }
 }
 
@@ -1318,3 +3799,625 @@ impl MessageId {
         self.link(channel_id, guild_id)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::MessageReaction;
+    use crate::json::{self, json};
+    use crate::model::channel::ReactionType;
+    use crate::model::id::EmojiId;
+    #[cfg(all(feature = "model", feature = "utils"))]
+    use crate::model::id::{ChannelId, UserId};
+
+    #[test]
+    fn message_reaction_round_trip() {
+        let reaction = MessageReaction {
+            count: 3,
+            me: true,
+            reaction_type: ReactionType::Custom {
+                animated: false,
+                id: EmojiId(600404340292059257),
+                name: Some("customemoji".to_string()),
+            },
+        };
+
+        let value = json::to_value(&reaction).unwrap();
+        assert_eq!(reaction, json::from_value::<MessageReaction>(value).unwrap());
+
+        let reaction = MessageReaction {
+            count: 0,
+            me: false,
+            reaction_type: ReactionType::Unicode("\u{1f44d}".to_string()),
+        };
+
+        let value = json::to_value(&reaction).unwrap();
+        assert_eq!(value, json!({"emoji": {"name": "\u{1f44d}"}}));
+        assert_eq!(reaction, json::from_value::<MessageReaction>(value).unwrap());
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn apply_reaction_remove() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        let current_user = UserId(1);
+        let emoji = ReactionType::Custom {
+            animated: false,
+            id: EmojiId(600404340292059257),
+            name: Some("customemoji".to_string()),
+        };
+        message.reactions.push(MessageReaction {
+            count: 2,
+            me: true,
+            reaction_type: emoji.clone(),
+        });
+
+        let mut reaction: crate::model::channel::Reaction = json::from_value(json!({
+            "channel_id": message.channel_id.to_string(),
+            "message_id": message.id.to_string(),
+            "user_id": "1",
+            "emoji": {"id": "600404340292059257", "name": null},
+        }))
+        .unwrap();
+
+        assert_eq!(message.reaction_role_state(emoji.clone()), Some(true));
+        assert!(message.apply_reaction_remove(&reaction, current_user));
+        assert_eq!(message.reaction_role_state(emoji.clone()), Some(false));
+
+        reaction.user_id = Some(UserId(2));
+        assert!(message.apply_reaction_remove(&reaction, current_user));
+        assert_eq!(message.reaction_role_state(emoji), None);
+        assert!(!message.apply_reaction_remove(&reaction, current_user));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn display_content() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        assert_eq!(message.display_content(), "");
+
+        message.embeds = vec![json::from_value(json!({"title": "Status"})).unwrap()];
+        assert_eq!(message.display_content(), "[embed: Status]");
+
+        message.embeds.push(json::from_value(json!({})).unwrap());
+        message.attachments = vec![json::from_value(json!({
+            "id": "1",
+            "filename": "image.png",
+            "size": 1,
+            "url": "https://cdn.discordapp.com/image.png",
+            "proxy_url": "https://media.discordapp.net/image.png",
+        }))
+        .unwrap()];
+        assert_eq!(message.display_content(), "[2 embeds] [attachment: image.png]");
+
+        message.content = "hello".to_string();
+        assert_eq!(message.display_content(), "hello");
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn content_snippet() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.content = "h\u{e9}llo w\u{f6}rld".to_string();
+
+        assert_eq!(message.content_snippet(1, 4), "\u{e9}llo");
+        assert_eq!(message.content_snippet(6, 100), "w\u{f6}rld");
+        assert_eq!(message.content_snippet(100, 5), "");
+        assert_eq!(message.content_snippet(0, 0), "");
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn automod_action() {
+        use super::MessageType;
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.embeds = vec![json::from_value(json!({
+            "type": "auto_moderation_message",
+            "description": "buy cheap stuff",
+            "fields": [
+                {"name": "rule_name", "value": "No spam", "inline": false},
+                {"name": "channel_id", "value": "1234", "inline": false},
+                {"name": "keyword", "value": "cheap*", "inline": false},
+                {"name": "keyword_matched_content", "value": "cheap", "inline": false},
+            ],
+        }))
+        .unwrap()];
+        assert!(message.automod_action().is_none());
+
+        message.kind = MessageType::AutoModerationAction;
+        let info = message.automod_action().unwrap();
+        assert_eq!(info.rule_name.as_deref(), Some("No spam"));
+        assert_eq!(info.channel_id, Some(ChannelId(1234)));
+        assert_eq!(info.content.as_deref(), Some("buy cheap stuff"));
+        assert_eq!(info.matched_keyword.as_deref(), Some("cheap*"));
+        assert_eq!(info.matched_content.as_deref(), Some("cheap"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils", feature = "cache"))]
+    fn content_safe() {
+        use crate::cache::Cache;
+        use crate::utils::ContentSafeOptions;
+
+        let cache = Cache::default();
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.content = "@everyone <@&1>".to_string();
+
+        let options = ContentSafeOptions::default();
+        assert_eq!(message.content_safe(&cache, &options), "@\u{200B}everyone @deleted-role");
+
+        let options = options.clean_role(false).clean_everyone(false);
+        assert_eq!(message.content_safe(&cache, &options), "@everyone <@&1>");
+
+        message.content = "<#5> <#6>".to_string();
+        message.mention_channels = vec![json::from_value(json!({
+            "id": "5",
+            "guild_id": "1",
+            "type": 0,
+            "name": "general",
+        }))
+        .unwrap()];
+        assert_eq!(message.content_safe(&cache, &options), "#general #deleted-channel");
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils", feature = "cache"))]
+    fn content_safe_default_keeps_previous_output() {
+        use crate::cache::Cache;
+        use crate::model::id::UserId;
+        use crate::utils::ContentSafeOptions;
+
+        let cache = Cache::default();
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.author.id = UserId(7);
+        message.author.name = "crab".to_string();
+        message.author.discriminator = 1234;
+        message.mentions = vec![message.author.clone()];
+        message.content = "<@7> <@!7> <@&1> @everyone @here <:ferris:3>".to_string();
+
+        assert_eq!(
+            message.content_safe(&cache, &ContentSafeOptions::default()),
+            "@crab#1234 @crab#1234 @deleted-role @\u{200B}everyone @\u{200B}here <:ferris:3>"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn check_embed_length() {
+        use super::Message;
+
+        let embeds = |author: &str, description: &str, footer: &str| {
+            let value = json!({"embeds": [{
+                "author": {"name": author},
+                "description": description,
+                "footer": {"text": footer},
+            }]});
+            value.as_object().unwrap().clone()
+        };
+
+        let map = embeds(&"a".repeat(256), &"b".repeat(4096), &"c".repeat(1649));
+        assert!(matches!(
+            Message::check_embed_length(&map),
+            Err(crate::Error::Model(crate::model::ModelError::EmbedTooLarge(1)))
+        ));
+
+        let map = embeds(&"a".repeat(257), "", "");
+        assert!(matches!(
+            Message::check_embed_length(&map),
+            Err(crate::Error::Model(crate::model::ModelError::EmbedFieldTooLong {
+                field: crate::model::channel::EmbedFieldKind::AuthorName,
+                len: 257,
+                limit: 256,
+            }))
+        ));
+
+        let map = embeds("", &"\u{e9}".repeat(4096), &"\u{e9}".repeat(1904));
+        assert!(Message::check_embed_length(&map).is_ok());
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn edit_rejects_large_embeds() {
+        use super::Message;
+        use crate::builder::{CreateEmbed, EditMessage};
+
+        let mut embed = CreateEmbed::default();
+        embed.description("a".repeat(4000));
+        embed.footer(|f| f.text("b".repeat(1000)));
+        embed.field("c".repeat(256), "d".repeat(745), false);
+
+        let mut builder = EditMessage::default();
+        builder.content("hello").set_embed(embed);
+
+        let map = json::hashmap_to_json_map(builder.0);
+        assert!(matches!(
+            Message::check_lengths(&map),
+            Err(crate::Error::Model(crate::model::ModelError::EmbedTooLarge(1)))
+        ));
+    }
+
+    #[test]
+    fn message_nonce() {
+        use super::MessageNonce;
+
+        let nonce: MessageNonce = json::from_value(json!("1234")).unwrap();
+        assert_eq!(nonce, MessageNonce::String("1234".to_string()));
+
+        let nonce: MessageNonce = json::from_value(json!(1234)).unwrap();
+        assert_eq!(nonce, MessageNonce::Number(1234));
+        assert_eq!(nonce.to_string(), "1234");
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn message_skips_absent_fields() {
+        let message = crate::utils::CustomMessage::new().build();
+
+        let value = json::to_value(&message).unwrap();
+        let map = value.as_object().unwrap();
+        assert!(!map.contains_key("guild_id"));
+        assert!(!map.contains_key("member"));
+        assert!(!map.contains_key("edited_timestamp"));
+
+        let message: super::Message = json::from_value(value).unwrap();
+        assert!(message.guild_id.is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn contains_word() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.content = "This CLASS is a b\u{200B}ad one, caf\u{e9}.".to_string();
+
+        assert!(!message.contains_word("ass"));
+        assert!(message.contains_word("class"));
+        assert!(message.contains_word("bad"));
+        assert!(message.contains_word("bad one"));
+        assert!(message.contains_word("Caf\u{c9}"));
+        assert!(!message.contains_word("caf"));
+        assert!(!message.contains_word(""));
+
+        assert!(message.contains_any_word(&["foo", "one"]));
+        assert!(!message.contains_any_word(&["foo", "his"]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn strip_prefixes() {
+        let bot_id = Some(UserId(5));
+        let mut message = crate::utils::CustomMessage::new().build();
+
+        message.content = "!ping".to_string();
+        assert_eq!(message.strip_prefixes(&["?", "!"], bot_id), Some("ping"));
+
+        message.content = "<@5> ping".to_string();
+        assert_eq!(message.strip_prefixes(&["!"], bot_id), Some("ping"));
+        assert_eq!(message.strip_prefixes(&["!"], None), None);
+
+        message.content = "<@!5>ping".to_string();
+        assert_eq!(message.strip_prefixes(&[], bot_id), Some("ping"));
+
+        message.content = "<@6> ping".to_string();
+        assert_eq!(message.strip_prefixes(&["!"], bot_id), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn invite_codes() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.content = "join https://discord.com/invite/AbC-1 or DISCORD.GG/xyz!".to_string();
+        assert_eq!(message.invite_codes(), vec!["AbC-1", "xyz"]);
+        assert!(message.contains_invite());
+
+        message.content =
+            "`discord.gg/a` ```\ndiscord.gg/b\n``` notdiscord.gg/c www.discordapp.com/invite/d"
+                .to_string();
+        assert_eq!(message.invite_codes(), vec!["d"]);
+
+        message.content = "discord.gg/ and discord.com/channels/1".to_string();
+        assert!(!message.contains_invite());
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn content_matches() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        let mut other = message.clone();
+        assert!(!message.content_matches(&other));
+
+        message.content = "Free Nitro here".to_string();
+        other.content = "  free n\u{200B}itro HERE\n".to_string();
+        assert!(message.content_matches(&other));
+
+        other.content = "free nitro there".to_string();
+        assert!(!message.content_matches(&other));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn age() {
+        use std::time::Duration;
+
+        use crate::model::Timestamp;
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        let an_hour_ago = Timestamp::now().unix_timestamp() - 3600;
+        message.timestamp = Timestamp::from_unix_timestamp(an_hour_ago).unwrap();
+
+        assert!(message.age() >= Duration::from_secs(3600));
+        assert!(message.is_older_than(Duration::from_secs(60)));
+        assert!(!message.is_older_than(Duration::from_secs(7200)));
+
+        message.timestamp = Timestamp::from_unix_timestamp(an_hour_ago + 7200).unwrap();
+        assert_eq!(message.age(), Duration::ZERO);
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn is_reply() {
+        use crate::model::channel::{MessageReference, MessageType};
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        assert!(!message.is_reply());
+        assert!(message.replied_author().is_none());
+
+        let mut replied = crate::utils::CustomMessage::new().build();
+        replied.author.name = "replied".to_string();
+
+        message.kind = MessageType::InlineReply;
+        message.message_reference = Some(MessageReference::from(&replied));
+        assert!(message.is_reply());
+        assert!(message.replied_author().is_none());
+
+        message.referenced_message = Some(Box::new(replied));
+        assert_eq!(message.replied_author().map(|author| author.name.as_str()), Some("replied"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn effective_author_name() {
+        use crate::model::id::WebhookId;
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.author.name = "user".to_string();
+        assert!(!message.is_webhook());
+        assert_eq!(message.effective_author_name(), "user");
+
+        message.member = Some(
+            json::from_value(json!({
+                "deaf": false,
+                "mute": false,
+                "nick": "nickname",
+                "roles": [],
+            }))
+            .unwrap(),
+        );
+        assert_eq!(message.effective_author_name(), "nickname");
+
+        message.webhook_id = Some(WebhookId(1));
+        message.author.name = "Webhook".to_string();
+        assert!(message.is_webhook());
+        assert_eq!(message.effective_author_name(), "Webhook");
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn last_activity() {
+        use crate::model::Timestamp;
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.timestamp = Timestamp::from_unix_timestamp(1_600_000_000).unwrap();
+        assert!(!message.was_edited());
+        assert_eq!(message.last_activity(), message.timestamp);
+
+        let edited = Timestamp::from_unix_timestamp(1_600_000_060).unwrap();
+        message.edited_timestamp = Some(edited);
+        assert!(message.was_edited());
+        assert_eq!(message.last_activity(), edited);
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn parse_content() {
+        use super::ContentToken;
+        use crate::model::id::{EmojiId, RoleId};
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.content =
+            "hi <@!1> and <@2>, see <#3> <@&4> <a:wave:5> <t:-60:R><t:7> <@x> \u{e9}<:bad name:6>"
+                .to_string();
+
+        assert_eq!(message.parse_content_with_offsets(), vec![
+            (0, ContentToken::Text("hi ".to_string())),
+            (3, ContentToken::UserMention(UserId(1))),
+            (8, ContentToken::Text(" and ".to_string())),
+            (13, ContentToken::UserMention(UserId(2))),
+            (17, ContentToken::Text(", see ".to_string())),
+            (23, ContentToken::ChannelMention(ChannelId(3))),
+            (27, ContentToken::Text(" ".to_string())),
+            (28, ContentToken::RoleMention(RoleId(4))),
+            (33, ContentToken::Text(" ".to_string())),
+            (34, ContentToken::CustomEmoji {
+                id: EmojiId(5),
+                name: "wave".to_string(),
+                animated: true,
+            }),
+            (44, ContentToken::Text(" ".to_string())),
+            (45, ContentToken::Timestamp {
+                unix: -60,
+                style: Some('R'),
+            }),
+            (54, ContentToken::Timestamp {
+                unix: 7,
+                style: None,
+            }),
+            (59, ContentToken::Text(" <@x> \u{e9}<:bad name:6>".to_string())),
+        ]);
+        assert_eq!(message.parse_content().len(), 14);
+    }
+
+    #[test]
+    fn message_type_is_system() {
+        use super::MessageType;
+
+        assert!(!MessageType::Regular.is_system());
+        assert!(!MessageType::ContextMenuCommand.is_system());
+        assert!(MessageType::PinsAdd.is_system());
+        assert!(MessageType::Unknown(99).is_system());
+
+        assert!(MessageType::Regular.is_deletable());
+        assert!(MessageType::MemberJoin.is_deletable());
+        assert!(!MessageType::GroupNameUpdate.is_deletable());
+        assert!(!MessageType::ThreadStarterMessage.is_deletable());
+    }
+
+    #[test]
+    fn message_type_unknown_round_trip() {
+        use super::MessageType;
+
+        let kind: MessageType = json::from_value(json!(19)).unwrap();
+        assert_eq!(kind, MessageType::InlineReply);
+
+        let kind: MessageType = json::from_value(json!(99)).unwrap();
+        assert_eq!(kind, MessageType::Unknown(99));
+        assert_eq!(kind.num(), 99);
+        assert_eq!(json::to_value(kind).unwrap(), json!(99));
+
+        assert_eq!(kind.label(), "Unknown");
+        assert_eq!(kind.to_string(), "Unknown (99)");
+        assert_eq!(MessageType::InlineReply.to_string(), "Reply");
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn reaction_count() {
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.reactions = vec![
+            MessageReaction {
+                count: 4,
+                me: true,
+                reaction_type: ReactionType::Custom {
+                    animated: false,
+                    id: EmojiId(1),
+                    name: Some("pog".to_string()),
+                },
+            },
+            MessageReaction {
+                count: 2,
+                me: false,
+                reaction_type: ReactionType::Unicode("\u{1f44d}".to_string()),
+            },
+        ];
+
+        assert_eq!(message.reaction_count(EmojiId(1)), 4);
+        assert!(message.has_reaction_from_me(EmojiId(1)));
+        assert_eq!(message.reaction_count('\u{1f44d}'), 2);
+        assert!(!message.has_reaction_from_me('\u{1f44d}'));
+        assert_eq!(message.reaction_count('\u{1f44e}'), 0);
+        assert!(!message.has_reaction_from_me('\u{1f44e}'));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn message_reference_link() {
+        use super::MessageReference;
+        use crate::model::id::{ChannelId, GuildId, MessageId};
+
+        let mut reference = MessageReference::from((ChannelId(2), MessageId(3)));
+        assert_eq!(reference.link().as_deref(), Some("https://discord.com/channels/@me/2/3"));
+
+        reference.guild_id = Some(GuildId(1));
+        assert_eq!(reference.link().as_deref(), Some("https://discord.com/channels/1/2/3"));
+
+        reference.message_id = None;
+        assert_eq!(reference.link(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn thread_id() {
+        use crate::model::channel::MessageFlags;
+        use crate::model::id::MessageId;
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.id = MessageId(5);
+        assert!(!message.has_thread());
+        assert_eq!(message.thread_id(), None);
+
+        message.flags = Some(MessageFlags::HAS_THREAD | MessageFlags::CROSSPOSTED);
+        assert!(message.has_thread());
+        assert_eq!(message.thread_id(), Some(ChannelId(5)));
+        assert!(message.is_crossposted());
+        assert!(!message.is_ephemeral());
+        assert!(!message.flags_contains(MessageFlags::HAS_THREAD | MessageFlags::LOADING));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn payload_overflow_length() {
+        use super::Message;
+        use crate::builder::CreateEmbed;
+
+        let mut embed = CreateEmbed::default();
+        embed.description("a".repeat(4000));
+        assert_eq!(Message::embed_overflow_length(&embed), None);
+        assert_eq!(Message::payload_overflow_length("hello", &[embed.clone()]), None);
+
+        embed.field("b".repeat(200), "c".repeat(1000), false);
+        embed.field("d".repeat(200), "e".repeat(1000), false);
+        assert_eq!(Message::embed_overflow_length(&embed), Some(400));
+
+        let content = "f".repeat(2001);
+        let embeds = [embed.clone(), embed];
+        assert_eq!(Message::payload_overflow_length(&content, &embeds), Some(1 + 6800));
+    }
+
+    #[test]
+    #[cfg(all(feature = "model", feature = "utils"))]
+    fn mentions_role_and_everyone() {
+        use crate::model::id::RoleId;
+
+        let mut message = crate::utils::CustomMessage::new().build();
+        message.mention_roles = vec![RoleId(7)];
+        assert!(message.mentions_role(7));
+        assert!(!message.mentions_role(RoleId(8)));
+
+        message.content = "`@everyone` ```\n@here\n```".to_string();
+        assert!(!message.mentions_everyone_effective());
+
+        message.content = "hey @here".to_string();
+        assert!(message.mentions_everyone_effective());
+
+        message.content = String::new();
+        message.mention_everyone = true;
+        assert!(message.mentions_everyone_effective());
+    }
+
+    #[test]
+    fn message_activity_kind_name() {
+        use super::MessageActivityKind;
+
+        let kind: MessageActivityKind = json::from_value(json!(5)).unwrap();
+        assert_eq!(kind.name(), "join_request");
+
+        let kind: MessageActivityKind = json::from_value(json!(4)).unwrap();
+        assert_eq!(kind, MessageActivityKind::Unknown);
+        assert_eq!(kind.name(), "unknown");
+    }
+
+    #[test]
+    #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
+    fn origin() {
+        use super::MessageOrigin;
+        use crate::cache::Cache;
+        use crate::model::channel::ChannelType;
+
+        let message = crate::utils::CustomMessage::new().build();
+        assert_eq!(message.origin(Cache::default()), MessageOrigin::Unknown);
+
+        assert!(MessageOrigin::from_channel_type(ChannelType::PrivateThread).is_thread());
+        assert!(MessageOrigin::from_channel_type(ChannelType::Stage).is_guild());
+        assert_eq!(MessageOrigin::from_channel_type(ChannelType::Private), MessageOrigin::Dm);
+        assert_eq!(MessageOrigin::from_channel_type(ChannelType::Category), MessageOrigin::Unknown);
+    }
+}
@@ -422,6 +422,27 @@ impl Serialize for ReactionType {
 
 #[cfg(feature = "model")]
 impl ReactionType {
+    /// Parses a unicode emoji, or a custom emoji in Discord's markup such as
+    /// `<:name:123>` or `<a:name:123>` for animated ones, ignoring surrounding
+    /// whitespace.
+    ///
+    /// This is useful for emojis read from user input or configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::channel::ReactionType;
+    ///
+    /// let reaction = ReactionType::parse(" <a:dance:600404340292059257> ").unwrap();
+    /// assert!(matches!(reaction, ReactionType::Custom { animated: true, .. }));
+    ///
+    /// assert!(ReactionType::parse("<:broken>").is_none());
+    /// ```
+    #[must_use]
+    pub fn parse(emoji: &str) -> Option<ReactionType> {
+        ReactionType::try_from(emoji.trim()).ok()
+    }
+
     /// Creates a data-esque display of the type. This is not very useful for
     /// displaying, as the primary client can not render it, but can be useful
     /// for debugging.
@@ -618,9 +639,16 @@ impl<'a> TryFrom<&'a str> for ReactionType {
 
         let mut split_iter = emoji_str.split(':');
 
-        let animated = split_iter.next().ok_or(ReactionConversionError)? == "a";
+        let animated = match split_iter.next() {
+            Some("a") => true,
+            Some("") => false,
+            _ => return Err(ReactionConversionError),
+        };
 
-        let name = split_iter.next().ok_or(ReactionConversionError)?.to_string().into();
+        let name = match split_iter.next() {
+            Some(name) if !name.is_empty() => Some(name.to_string()),
+            _ => return Err(ReactionConversionError),
+        };
 
         let id = split_iter
             .next()
@@ -628,6 +656,10 @@ impl<'a> TryFrom<&'a str> for ReactionType {
             .ok_or(ReactionConversionError)?
             .into();
 
+        if split_iter.next().is_some() {
+            return Err(ReactionConversionError);
+        }
+
         Ok(ReactionType::Custom {
             animated,
             id,
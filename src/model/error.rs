@@ -3,6 +3,7 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use super::channel::EmbedFieldKind;
 use super::Permissions;
 
 /// An error returned from the [`model`] module.
@@ -73,6 +74,18 @@ pub enum Error {
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(usize),
+    /// When an embed has over 25 fields.
+    EmbedFieldAmount,
+    /// Indicates that a part of an embed exceeds its own maximum length,
+    /// providing the length of the part and its limit in unicode code points.
+    EmbedFieldTooLong {
+        /// The part of the embed that is too long.
+        field: EmbedFieldKind,
+        /// The length of the part.
+        len: usize,
+        /// The maximum length of the part.
+        limit: usize,
+    },
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -101,11 +114,25 @@ pub enum Error {
     /// [`ChannelId`]: super::id::ChannelId
     /// [`Cache`]: crate::cache::Cache
     ChannelNotFound,
+    /// An indication that a guild [`Emoji`] could not be found by name.
+    ///
+    /// [`Emoji`]: super::guild::Emoji
+    EmojiNotFound,
     /// An indication that a [`Message`] has already been crossposted,
     /// and cannot be crossposted twice.
     ///
     /// [`Message`]: super::channel::Message
     MessageAlreadyCrossposted,
+    /// An indication that a [`Message`] does not exist anymore, for instance
+    /// because it was already deleted.
+    ///
+    /// [`Message`]: super::channel::Message
+    MessageDeleted,
+    /// An indication that a [`Message`] cannot be pinned because its channel
+    /// already has the maximum of 50 pinned messages.
+    ///
+    /// [`Message`]: super::channel::Message
+    TooManyPins,
     /// An indication that you cannot crosspost a [`Message`].
     ///
     /// For instance, you cannot crosspost a system message or a
@@ -195,10 +222,15 @@ impl fmt::Display for Error {
             Self::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
             Self::EmbedAmount => f.write_str("Too many embeds in a message."),
             Self::EmbedTooLarge(_) => f.write_str("Embed too large."),
+            Self::EmbedFieldAmount => f.write_str("Too many fields in an embed."),
+            Self::EmbedFieldTooLong {
+                ..
+            } => f.write_str("Embed field too long."),
             Self::GuildNotFound => f.write_str("Guild not found in the cache."),
             Self::RoleNotFound => f.write_str("Role not found in the cache."),
             Self::MemberNotFound => f.write_str("Member not found in the cache."),
             Self::ChannelNotFound => f.write_str("Channel not found in the cache."),
+            Self::EmojiNotFound => f.write_str("Emoji not found in the guild."),
             Self::Hierarchy => f.write_str("Role hierarchy prevents this action."),
             Self::InvalidChannelType => f.write_str("The channel cannot perform the action."),
             Self::InvalidPermissions(_) => f.write_str("Invalid permissions."),
@@ -207,6 +239,8 @@ impl fmt::Display for Error {
             Self::WrongGuild => f.write_str("Provided member or channel is from the wrong guild."),
             Self::MessageTooLong(_) => f.write_str("Message too large."),
             Self::MessageAlreadyCrossposted => f.write_str("Message already crossposted."),
+            Self::MessageDeleted => f.write_str("Message was already deleted."),
+            Self::TooManyPins => f.write_str("Too many pinned messages in the channel."),
             Self::CannotCrosspostMessage => f.write_str("Cannot crosspost this message type."),
             Self::MessagingBot => f.write_str("Attempted to message another bot user."),
             Self::NameTooShort => f.write_str("Name is under the character limit."),
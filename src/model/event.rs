@@ -420,7 +420,7 @@ pub struct MessageUpdateEvent {
     pub attachments: Option<Vec<Attachment>>,
     pub embeds: Option<Vec<Embed>>,
     pub reactions: Option<Vec<MessageReaction>>,
-    pub nonce: Option<String>, // TODO: Is this a Message field that can even change?
+    pub nonce: Option<MessageNonce>, // TODO: Is this a Message field that can even change?
     pub pinned: Option<bool>,
     pub kind: Option<MessageType>, // TODO: Is this a Message field that can even change?
     pub flags: Option<MessageFlags>,
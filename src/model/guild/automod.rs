@@ -318,6 +318,28 @@ pub struct ActionExecution {
     pub matched_content: Option<String>,
 }
 
+/// Details of a triggered rule, parsed from the embed of an auto moderation
+/// system message.
+///
+/// These messages are sent to the channel configured by an [`Action::Alert`].
+/// See [`Message::automod_action`].
+///
+/// [`Message::automod_action`]: crate::model::channel::Message::automod_action
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AutoModActionInfo {
+    /// Name of the rule which was triggered.
+    pub rule_name: Option<String>,
+    /// ID of the channel in which the user content was posted.
+    pub channel_id: Option<ChannelId>,
+    /// User generated text content which triggered the rule.
+    pub content: Option<String>,
+    /// Word or phrase configured in the rule that triggered the rule.
+    pub matched_keyword: Option<String>,
+    /// Substring in content that triggered the rule.
+    pub matched_content: Option<String>,
+}
+
 /// Helper struct for the (de)serialization of `Action`.
 #[derive(Deserialize, Serialize)]
 #[serde(rename = "ActionMetadata")]
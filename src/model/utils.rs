@@ -29,6 +29,12 @@ pub fn is_false(v: &bool) -> bool {
     !v
 }
 
+/// Helper function for `#[serde(skip_serializing_if = "is_zero")]`
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn is_zero(v: &u64) -> bool {
+    *v == 0
+}
+
 /// Used with `#[serde(with = "emojis")]`
 pub mod emojis {
     use std::collections::HashMap;
@@ -201,6 +201,74 @@ impl Colour {
     pub fn hex(self) -> String {
         format!("{:06X}", self.0)
     }
+
+    /// Parses a Colour from a hexadecimal string such as `#1abc9c` or
+    /// `1ABC9C`, as copied from design tools.
+    ///
+    /// The leading `#` is optional and the digits are case-insensitive.
+    /// Returns [`None`] if the string is not made of exactly 6 hexadecimal
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hex_str("#1abc9c"), Some(Colour::new(0x1ABC9C)));
+    /// assert_eq!(Colour::from_hex_str("1ABC9C"), Some(Colour::new(0x1ABC9C)));
+    /// assert_eq!(Colour::from_hex_str("#1abc9"), None);
+    /// ```
+    #[must_use]
+    pub fn from_hex_str(s: &str) -> Option<Colour> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u32::from_str_radix(digits, 16).ok().map(Colour)
+    }
+
+    /// Looks up one of the named colours defined as constants on [`Colour`],
+    /// such as `"gold"` or `"Dark Green"`.
+    ///
+    /// Names are matched case-insensitively, treating spaces and hyphens like
+    /// underscores and `gray` like `grey`. Returns [`None`] for unknown names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_name("discord-blurple"), Some(Colour::DISCORD_BLURPLE));
+    /// assert_eq!(Colour::from_name("Light Gray"), Some(Colour::LIGHT_GREY));
+    /// assert_eq!(Colour::from_name("octarine"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Colour> {
+        let name =
+            name.trim().to_ascii_uppercase().replace(&[' ', '-'][..], "_").replace("GRAY", "GREY");
+
+        NAMED_COLOURS.iter().find(|(known, _)| *known == name).map(|&(_, colour)| colour)
+    }
+
+    /// Returns the Colour as a `#rrggbb` string, the format accepted by
+    /// [`Self::from_hex_str`].
+    ///
+    /// Unlike [`Self::hex`], the string is prefixed with `#` and uses
+    /// lowercase digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::new(6573123).to_hex_str(), "#644c43");
+    /// ```
+    #[must_use]
+    pub fn to_hex_str(self) -> String {
+        format!("#{:06x}", self.0)
+    }
 }
 
 impl From<i32> for Colour {
@@ -290,6 +358,10 @@ impl Colour {
     pub const DARK_TEAL: Colour = Colour(0x11806A);
     /// Creates a new [`Colour`], setting its RGB value to `(84, 110, 122)`.
     pub const DARKER_GREY: Colour = Colour(0x546E7A);
+    /// Creates a new [`Colour`], setting its RGB value to `(88, 101, 242)`.
+    ///
+    /// This is Discord's current blurple, unlike [`Self::BLURPLE`].
+    pub const DISCORD_BLURPLE: Colour = Colour(0x5865F2);
     /// Creates a new [`Colour`], setting its RGB value to `(250, 177, 237)`.
     pub const FABLED_PINK: Colour = Colour(0xFAB1ED);
     /// Creates a new [`Colour`], setting its RGB value to `(136, 130, 196)`.
@@ -298,6 +370,8 @@ impl Colour {
     pub const FOOYOO: Colour = Colour(0x11CA80);
     /// Creates a new [`Colour`], setting its RGB value to `(241, 196, 15)`.
     pub const GOLD: Colour = Colour(0xF1C40F);
+    /// Creates a new [`Colour`], setting its RGB value to `(46, 204, 113)`.
+    pub const GREEN: Colour = Colour(0x2ECC71);
     /// Creates a new [`Colour`], setting its RGB value to `(186, 218, 85)`.
     pub const KERBAL: Colour = Colour(0xBADA55);
     /// Creates a new [`Colour`], setting its RGB value to `(151, 156, 159)`.
@@ -322,6 +396,40 @@ impl Colour {
     pub const TEAL: Colour = Colour(0x1ABC9C);
 }
 
+/// The named colours of [`Colour::from_name`].
+const NAMED_COLOURS: &[(&str, Colour)] = &[
+    ("BLITZ_BLUE", Colour::BLITZ_BLUE),
+    ("BLUE", Colour::BLUE),
+    ("BLURPLE", Colour::BLURPLE),
+    ("DARK_BLUE", Colour::DARK_BLUE),
+    ("DARK_GOLD", Colour::DARK_GOLD),
+    ("DARK_GREEN", Colour::DARK_GREEN),
+    ("DARK_GREY", Colour::DARK_GREY),
+    ("DARK_MAGENTA", Colour::DARK_MAGENTA),
+    ("DARK_ORANGE", Colour::DARK_ORANGE),
+    ("DARK_PURPLE", Colour::DARK_PURPLE),
+    ("DARK_RED", Colour::DARK_RED),
+    ("DARK_TEAL", Colour::DARK_TEAL),
+    ("DARKER_GREY", Colour::DARKER_GREY),
+    ("DISCORD_BLURPLE", Colour::DISCORD_BLURPLE),
+    ("FABLED_PINK", Colour::FABLED_PINK),
+    ("FADED_PURPLE", Colour::FADED_PURPLE),
+    ("FOOYOO", Colour::FOOYOO),
+    ("GOLD", Colour::GOLD),
+    ("GREEN", Colour::GREEN),
+    ("KERBAL", Colour::KERBAL),
+    ("LIGHT_GREY", Colour::LIGHT_GREY),
+    ("LIGHTER_GREY", Colour::LIGHTER_GREY),
+    ("MAGENTA", Colour::MAGENTA),
+    ("MEIBE_PINK", Colour::MEIBE_PINK),
+    ("ORANGE", Colour::ORANGE),
+    ("PURPLE", Colour::PURPLE),
+    ("RED", Colour::RED),
+    ("ROHRKATZE_BLUE", Colour::ROHRKATZE_BLUE),
+    ("ROSEWATER", Colour::ROSEWATER),
+    ("TEAL", Colour::TEAL),
+];
+
 impl Default for Colour {
     /// Creates a default value for a [`Colour`], setting the inner value to `0`.
     fn default() -> Colour {
@@ -458,4 +566,24 @@ mod test {
         assert_eq!(Colour::from(7u32).0, 7);
         assert_eq!(Colour::from(7u64).0, 7);
     }
+
+    #[test]
+    fn hex_str() {
+        assert_eq!(Colour::from_hex_str("#00ff7F"), Some(Colour::new(0x00FF7F)));
+        assert_eq!(Colour::from_hex_str("000000"), Some(Colour::new(0)));
+        assert_eq!(Colour::from_hex_str("#+fffff"), None);
+        assert_eq!(Colour::from_hex_str("#fffffff"), None);
+        assert_eq!(Colour::from_hex_str("##ffffff"), None);
+
+        let colour = Colour::new(0x336123);
+        assert_eq!(Colour::from_hex_str(&colour.to_hex_str()), Some(colour));
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Colour::from_name("GOLD"), Some(Colour::GOLD));
+        assert_eq!(Colour::from_name(" dark gray "), Some(Colour::DARK_GREY));
+        assert_eq!(Colour::from_name("rohrkatze-blue"), Some(Colour::ROHRKATZE_BLUE));
+        assert_eq!(Colour::from_name(""), None);
+    }
 }
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 
 use crate::cache::Cache;
-use crate::model::channel::Channel;
+use crate::model::channel::{Channel, ChannelMention};
 use crate::model::id::GuildId;
 use crate::model::mention::Mention;
 use crate::model::user::User;
+use crate::utils::parse_emoji;
 
 /// Struct that allows to alter [`content_safe`]'s behaviour.
 #[derive(Clone, Debug)]
@@ -14,6 +15,8 @@ pub struct ContentSafeOptions {
     clean_channel: bool,
     clean_here: bool,
     clean_everyone: bool,
+    clean_emoji: bool,
+    escape_markdown: bool,
     show_discriminator: bool,
     guild_reference: Option<GuildId>,
 }
@@ -91,10 +94,39 @@ impl ContentSafeOptions {
 
         self
     }
+
+    /// If set, [`content_safe`] will replace custom emojis (`<:name:id>` or
+    /// `<a:name:id>`) with their name wrapped in colons (`:name:`).
+    ///
+    /// The name is taken from the emoji markup itself, so this does not
+    /// require the emoji to be cached.
+    ///
+    /// Defaults to `false`, leaving custom emojis untouched.
+    #[must_use]
+    pub fn clean_emoji(mut self, b: bool) -> Self {
+        self.clean_emoji = b;
+
+        self
+    }
+
+    /// If set, [`content_safe`] will escape markdown in the content with
+    /// backslashes, see [`escape_markdown`], so that it is displayed
+    /// literally.
+    ///
+    /// Only the text written by the user is escaped: the names that replace
+    /// mentions and emojis, as well as mentions and emojis that are kept, are
+    /// left as-is.
+    #[must_use]
+    pub fn escape_markdown(mut self, b: bool) -> Self {
+        self.escape_markdown = b;
+
+        self
+    }
 }
 
 impl Default for ContentSafeOptions {
-    /// Instantiates with all options set to `true`.
+    /// Instantiates with all options set to `true`, except for
+    /// [`Self::clean_emoji`] and [`Self::escape_markdown`].
     fn default() -> Self {
         ContentSafeOptions {
             clean_role: true,
@@ -102,14 +134,17 @@ impl Default for ContentSafeOptions {
             clean_channel: true,
             clean_here: true,
             clean_everyone: true,
+            clean_emoji: false,
+            escape_markdown: false,
             show_discriminator: true,
             guild_reference: None,
         }
     }
 }
 
-/// Transforms role, channel, user, `@everyone` and `@here` mentions
-/// into raw text by using the [`Cache`] and the users passed in with `users`.
+/// Transforms role, channel, user, `@everyone` and `@here` mentions and,
+/// optionally, custom emojis into raw text by using the [`Cache`] and the
+/// users passed in with `users`.
 ///
 /// [`ContentSafeOptions`] decides what kind of mentions should be filtered
 /// and how the raw-text will be displayed.
@@ -147,7 +182,19 @@ pub fn content_safe(
     options: &ContentSafeOptions,
     users: &[User],
 ) -> String {
-    let mut content = clean_mentions(&cache, s, options, users);
+    content_safe_with_channels(cache, s, options, users, &[])
+}
+
+/// Like [`content_safe`], but channel mentions are resolved using the passed
+/// `channels` before falling back to the cache.
+pub(crate) fn content_safe_with_channels(
+    cache: impl AsRef<Cache>,
+    s: impl AsRef<str>,
+    options: &ContentSafeOptions,
+    users: &[User],
+    channels: &[ChannelMention],
+) -> String {
+    let mut content = clean_mentions(&cache, s, options, users, channels);
 
     if options.clean_here {
         content = content.replace("@here", "@\u{200B}here");
@@ -165,6 +212,7 @@ fn clean_mentions(
     s: impl AsRef<str>,
     options: &ContentSafeOptions,
     users: &[User],
+    channels: &[ChannelMention],
 ) -> String {
     let s = s.as_ref();
     let mut content = String::with_capacity(s.len());
@@ -175,11 +223,10 @@ fn clean_mentions(
         if b1 == "<" {
             if let Some(&(idx2, b2)) = brackets.peek() {
                 if b2 == ">" {
-                    content.push_str(&s[progress..idx1]);
+                    push_text(&mut content, &s[progress..idx1], options);
                     let mention_str = &s[idx1..=idx2];
 
                     // Don't waste time parsing if we're not going to clean the mention anyway
-                    // NOTE: Emoji mentions aren't cleaned.
                     let mut chars = mention_str.chars();
                     chars.next();
                     let should_parse = match chars.next() {
@@ -196,36 +243,89 @@ fn clean_mentions(
 
                     // I wish let_chains were stabilized :(
                     let mut cleaned = false;
+                    if options.clean_emoji {
+                        if let Some(emoji) = parse_emoji(mention_str) {
+                            content.push(':');
+                            content.push_str(&emoji.name);
+                            content.push(':');
+                            cleaned = true;
+                        }
+                    }
                     if should_parse {
                         // NOTE: numeric strings that are too large to fit into u64 will not parse
                         // correctly and will be left unchanged.
                         if let Ok(mention) = mention_str.parse() {
-                            content.push_str(&clean_mention(&cache, mention, options, users));
+                            content.push_str(&clean_mention(
+                                &cache, mention, options, users, channels,
+                            ));
                             cleaned = true;
                         }
                     }
                     if !cleaned {
-                        content.push_str(mention_str);
+                        if mention_str.parse::<Mention>().is_ok()
+                            || parse_emoji(mention_str).is_some()
+                        {
+                            content.push_str(mention_str);
+                        } else {
+                            push_text(&mut content, mention_str, options);
+                        }
                     }
                     progress = idx2 + 1;
                 }
             }
         }
     }
-    content.push_str(&s[progress..]);
+    push_text(&mut content, &s[progress..], options);
     content
 }
 
+fn push_text(content: &mut String, text: &str, options: &ContentSafeOptions) {
+    if options.escape_markdown {
+        content.push_str(&escape_markdown(text));
+    } else {
+        content.push_str(text);
+    }
+}
+
+/// Escapes the markdown characters `*`, `_`, `~`, `` ` ``, `|` and `>`, as well
+/// as backslashes, with a backslash, so that the text is displayed literally
+/// by Discord.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::escape_markdown;
+///
+/// assert_eq!(escape_markdown("**bold** ||spoiler||"), r"\*\*bold\*\* \|\|spoiler\|\|");
+/// ```
+#[must_use]
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '~' | '`' | '|' | '>') {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
 fn clean_mention(
     cache: impl AsRef<Cache>,
     mention: Mention,
     options: &ContentSafeOptions,
     users: &[User],
+    channels: &[ChannelMention],
 ) -> Cow<'static, str> {
     let cache = cache.as_ref();
     match mention {
         Mention::Channel(id) => {
-            if let Some(Channel::Guild(channel)) = id.to_channel_cached(&cache) {
+            if let Some(channel) = channels.iter().find(|c| c.id == id) {
+                format!("#{}", channel.name).into()
+            } else if let Some(Channel::Guild(channel)) = id.to_channel_cached(&cache) {
                 format!("#{}", channel.name).into()
             } else {
                 "#deleted-channel".into()
@@ -534,5 +634,34 @@ mod tests {
 
         let options = options.clean_here(false);
         assert_eq!(with_here_mention, content_safe(&cache, with_here_mention, &options, &[]));
+
+        // Custom emojis
+        let with_emojis = "<:ferris:302516740095606785> <a:crab:302516740095606786> \
+        <:broken:> <:invalid:abc> :plain:";
+
+        let without_emojis = ":ferris: :crab: <:broken:> <:invalid:abc> :plain:";
+
+        assert_eq!(with_emojis, content_safe(&cache, with_emojis, &options, &[]));
+
+        let options = options.clean_emoji(true);
+        assert_eq!(without_emojis, content_safe(&cache, with_emojis, &options, &[]));
+    }
+
+    #[test]
+    fn test_content_safe_escape_markdown() {
+        let cache = Arc::new(Cache::default());
+        let options = ContentSafeOptions::default().escape_markdown(true);
+
+        let content = "**hi** <@&1> <b_c> `x` > y";
+        assert_eq!(
+            r"\*\*hi\*\* @deleted-role <b\_c\> \`x\` \> y",
+            content_safe(&cache, content, &options, &[])
+        );
+
+        let options = options.clean_role(false);
+        assert_eq!(
+            r"\*\*hi\*\* <@&1> <b\_c\> \`x\` \> y",
+            content_safe(&cache, content, &options, &[])
+        );
     }
 }
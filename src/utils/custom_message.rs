@@ -1,4 +1,3 @@
-use crate::json::NULL;
 use crate::model::prelude::*;
 use crate::model::Timestamp;
 
@@ -264,7 +263,7 @@ fn dummy_message() -> Message {
         mention_roles: Vec::new(),
         mention_channels: Vec::new(),
         mentions: Vec::new(),
-        nonce: NULL,
+        nonce: None,
         pinned: false,
         reactions: Vec::new(),
         tts: false,
@@ -277,6 +276,7 @@ fn dummy_message() -> Message {
         sticker_items: Vec::new(),
         referenced_message: None,
         interaction: None,
+        interaction_metadata: None,
         components: vec![],
         application_id: None,
         thread: None,
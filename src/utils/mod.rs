@@ -30,6 +30,7 @@ use std::fs::File;
 use std::io::Read;
 use std::path::Path;
 
+use crate::constants;
 use crate::internal::prelude::*;
 use crate::model::id::EmojiId;
 use crate::model::misc::EmojiIdentifier;
@@ -458,6 +459,115 @@ pub fn shard_id(guild_id: impl Into<u64>, shard_count: u64) -> u64 {
     (guild_id.into() >> 22) % shard_count
 }
 
+/// Splits content into chunks that each fit into a single message, i.e. are
+/// at most [`MESSAGE_CODE_LIMIT`] unicode code points long.
+///
+/// Chunks are broken between lines where possible, and otherwise between
+/// words. When a chunk ends inside a fenced code block, the block is closed at
+/// the end of the chunk and re-opened, with the same language, at the start of
+/// the next one.
+///
+/// Content that already fits is returned as the only chunk.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::split_message;
+///
+/// let lines = vec!["a line of output"; 200].join("\n");
+/// let chunks = split_message(&lines);
+///
+/// assert_eq!(chunks.len(), 2);
+/// assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 2000));
+/// ```
+///
+/// [`MESSAGE_CODE_LIMIT`]: crate::constants::MESSAGE_CODE_LIMIT
+#[must_use]
+pub fn split_message(content: &str) -> Vec<String> {
+    // Room for closing a code block at the end of a chunk.
+    const FENCE_CLOSE: &str = "\n```";
+
+    let limit = constants::MESSAGE_CODE_LIMIT;
+    if content.chars().count() <= limit {
+        return vec![content.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    // The line opening the code block the content is currently in, if any.
+    let mut fence: Option<String> = None;
+
+    for mut line in content.split_inclusive('\n') {
+        let fence_after = if line.matches("```").count() % 2 == 0 {
+            fence.clone()
+        } else if fence.is_some() {
+            None
+        } else {
+            Some(code_block_opening(line))
+        };
+
+        let reserved = if fence.is_some() || fence_after.is_some() { FENCE_CLOSE.len() } else { 0 };
+        let room = |chunk: &String| limit.saturating_sub(chunk.chars().count() + reserved);
+
+        if line.chars().count() > room(&chunk) && !chunk.is_empty() {
+            push_chunk(&mut chunks, &mut chunk, fence.as_deref());
+        }
+
+        // A single line too long for a message is broken between words.
+        while line.chars().count() > room(&chunk) {
+            let max = room(&chunk).max(1);
+            let end = line.char_indices().nth(max).map_or(line.len(), |(index, _)| index);
+            let end = match line[..end].rfind(char::is_whitespace) {
+                Some(space) if space > 0 => space + 1,
+                _ => end,
+            };
+
+            chunk.push_str(&line[..end]);
+            line = &line[end..];
+            push_chunk(&mut chunks, &mut chunk, fence.as_deref());
+        }
+
+        chunk.push_str(line);
+        fence = fence_after;
+    }
+
+    if !chunk.trim().is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+/// Returns the start of a code block opened on the given line, i.e. the fence
+/// and its language, e.g. ` ```rust `.
+fn code_block_opening(line: &str) -> String {
+    let rest = line.rfind("```").map_or("", |index| &line[index + 3..]);
+    let language = rest.split_whitespace().next().filter(|language| language.len() <= 32);
+
+    format!("```{}", language.unwrap_or(""))
+}
+
+/// Moves a finished chunk into `chunks`, closing the code block it ends in
+/// and re-opening it in the next chunk.
+fn push_chunk(chunks: &mut Vec<String>, chunk: &mut String, fence: Option<&str>) {
+    let next = fence.map_or_else(String::new, |fence| fence.to_string() + "\n");
+
+    if fence.is_some() {
+        if !chunk.ends_with('\n') {
+            chunk.push('\n');
+        }
+        chunk.push_str("```");
+    } else {
+        chunk.truncate(chunk.trim_end().len());
+    }
+
+    if chunk.is_empty() {
+        *chunk = next;
+    } else {
+        chunks.push(std::mem::replace(chunk, next));
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -502,6 +612,30 @@ mod test {
         assert_eq!(parsed, ["a", "b c", "d", "e f", "g"]);
     }
 
+    #[test]
+    fn test_split_message() {
+        assert_eq!(split_message("short"), ["short"]);
+
+        let words = vec!["word"; 500].join(" ");
+        let chunks = split_message(&words);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 2000));
+        assert!(chunks.iter().all(|chunk| chunk.ends_with("word")));
+
+        let code = format!("Output:\n```rust\n{}```\nDone", "let x = 1;\n".repeat(300));
+        let chunks = split_message(&code);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].starts_with("Output:\n```rust\n") && chunks[0].ends_with("\n```"));
+        assert!(chunks[1].starts_with("```rust\nlet x = 1;\n") && chunks[1].ends_with("```\nDone"));
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 2000));
+
+        let unbroken = "\u{e9}".repeat(4500);
+        let chunks = split_message(&unbroken);
+        assert_eq!(chunks.iter().map(|chunk| chunk.chars().count()).collect::<Vec<_>>(), [
+            2000, 2000, 500
+        ]);
+    }
+
     #[test]
     fn test_webhook_parser() {
         let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV".parse().unwrap();
@@ -76,6 +76,30 @@ fn str_to_reaction_type_mangled_4() {
     ReactionType::try_from(emoji_str).unwrap_err();
 }
 
+#[test]
+fn str_to_reaction_type_mangled_5() {
+    for emoji_str in ["<x:somestuff:1234>", "<::1234>", "<:somestuff:1234:5678>"] {
+        ReactionType::try_from(emoji_str).unwrap_err();
+    }
+}
+
+#[test]
+#[cfg(feature = "model")]
+fn parse_reaction_type() {
+    let reaction = ReactionType::parse(" <a:customemoji2:600409340292059257>\n").unwrap();
+    let reaction2 = ReactionType::Custom {
+        animated: true,
+        id: EmojiId(600409340292059257),
+        name: Some("customemoji2".to_string()),
+    };
+    assert_eq!(reaction, reaction2);
+
+    let reaction = ReactionType::parse("\u{1f34e}").unwrap();
+    assert_eq!(reaction, ReactionType::Unicode("\u{1f34e}".to_string()));
+
+    assert_eq!(ReactionType::parse("  "), None);
+}
+
 #[test]
 fn str_fromstr() {
     let emoji_str = "<:somestuff:1234";